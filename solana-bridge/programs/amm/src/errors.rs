@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::math::MathError;
+
+/// Every failure the AMM program can report. Codes are `7000 + discriminant`,
+/// clear of the bridge's 6000-6799, so a failed CPI from the bridge decodes
+/// unambiguously; add new variants at the end and never renumber.
+#[error_code(offset = 7000)]
+pub enum AmmError {
+    #[msg("Amplification coefficient is outside the allowed range")]
+    InvalidAmplification,
+    #[msg("Swap fee exceeds the maximum allowed")]
+    InvalidFee,
+    #[msg("Pool mints must be distinct, ordered, and have 6 decimals")]
+    InvalidPoolMints,
+    #[msg("Token account mint does not match the pool")]
+    InvalidTokenAccount,
+    #[msg("Amount must be non-zero")]
+    ZeroAmount,
+    #[msg("Output is below the minimum accepted amount")]
+    SlippageExceeded,
+    #[msg("Pool does not hold enough liquidity")]
+    InsufficientLiquidity,
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+    #[msg("Invariant calculation did not converge")]
+    NotConverged,
+    #[msg("Signer is not the AMM authority")]
+    Unauthorized,
+    #[msg("Amplification was ramped too recently; see the log for when it may be again")]
+    RampLocked,
+    #[msg("Ramp must last at least a day and change A by at most 10 times")]
+    InvalidRamp,
+}
+
+impl From<MathError> for AmmError {
+    fn from(error: MathError) -> Self {
+        match error {
+            MathError::Overflow => AmmError::MathOverflow,
+            MathError::InsufficientLiquidity => AmmError::InsufficientLiquidity,
+            MathError::ZeroAmount => AmmError::ZeroAmount,
+            MathError::NotConverged => AmmError::NotConverged,
+        }
+    }
+}
+
+impl From<MathError> for Error {
+    fn from(error: MathError) -> Self {
+        AmmError::from(error).into()
+    }
+}
@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
+
+use crate::errors::AmmError;
+use crate::events::LiquidityAdded;
+use crate::math;
+use crate::state::Pool;
+
+#[derive(Accounts)]
+pub struct AddLiquidity<'info> {
+    pub provider: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Pool::SEED, pool.mint_a.as_ref(), pool.mint_b.as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(mut, address = pool.vault_a)]
+    pub vault_a: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, address = pool.vault_b)]
+    pub vault_b: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, address = pool.lp_mint)]
+    pub lp_mint: Box<Account<'info, Mint>>,
+
+    #[account(mut, token::mint = pool.mint_a, token::authority = provider)]
+    pub source_a: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, token::mint = pool.mint_b, token::authority = provider)]
+    pub source_b: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, token::mint = lp_mint)]
+    pub lp_destination: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<AddLiquidity>,
+    amount_a: u64,
+    amount_b: u64,
+    min_lp_out: u64,
+) -> Result<()> {
+    let accounts = ctx.accounts;
+    let lp_supply = accounts.lp_mint.supply;
+    // The first deposit sets the price, so it must hold both tokens.
+    if lp_supply == 0 {
+        require!(amount_a > 0 && amount_b > 0, AmmError::ZeroAmount);
+    } else {
+        require!(amount_a > 0 || amount_b > 0, AmmError::ZeroAmount);
+    }
+
+    let pool = &accounts.pool;
+    let now = Clock::get()?.unix_timestamp;
+    let minted = math::lp_tokens_to_mint(
+        (pool.reserve_a, pool.reserve_b),
+        (amount_a, amount_b),
+        lp_supply,
+        pool.current_amp(now),
+        pool.fee_bps,
+    )?;
+    require!(minted > 0, AmmError::ZeroAmount);
+    require!(minted >= min_lp_out, AmmError::SlippageExceeded);
+
+    for (source, vault, amount) in [
+        (&accounts.source_a, &accounts.vault_a, amount_a),
+        (&accounts.source_b, &accounts.vault_b, amount_b),
+    ] {
+        if amount == 0 {
+            continue;
+        }
+        token::transfer(
+            CpiContext::new(
+                accounts.token_program.to_account_info(),
+                Transfer {
+                    from: source.to_account_info(),
+                    to: vault.to_account_info(),
+                    authority: accounts.provider.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+    }
+    let seeds = pool.signer_seeds();
+    token::mint_to(
+        CpiContext::new_with_signer(
+            accounts.token_program.to_account_info(),
+            MintTo {
+                mint: accounts.lp_mint.to_account_info(),
+                to: accounts.lp_destination.to_account_info(),
+                authority: pool.to_account_info(),
+            },
+            &[&seeds],
+        ),
+        minted,
+    )?;
+
+    let pool = &mut accounts.pool;
+    pool.reserve_a = pool
+        .reserve_a
+        .checked_add(amount_a)
+        .ok_or(AmmError::MathOverflow)?;
+    pool.reserve_b = pool
+        .reserve_b
+        .checked_add(amount_b)
+        .ok_or(AmmError::MathOverflow)?;
+
+    emit!(LiquidityAdded {
+        pool: pool.key(),
+        provider: accounts.provider.key(),
+        amount_a,
+        amount_b,
+        lp_minted: minted,
+    });
+    Ok(())
+}
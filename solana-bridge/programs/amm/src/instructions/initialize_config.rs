@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::state::AmmConfig;
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    // `init` fails if the config already exists, so it can only be set once.
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + AmmConfig::INIT_SPACE,
+        seeds = [AmmConfig::SEED],
+        bump
+    )]
+    pub config: Account<'info, AmmConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeConfig>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.authority = ctx.accounts.authority.key();
+    config.bump = ctx.bumps.config;
+
+    msg!("AMM authority set to {}", config.authority);
+    Ok(())
+}
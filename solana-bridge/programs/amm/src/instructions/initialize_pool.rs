@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::errors::AmmError;
+use crate::events::PoolInitialized;
+use crate::math::TOKEN_DECIMALS;
+use crate::state::{AmmConfig, Pool};
+
+#[derive(Accounts)]
+pub struct InitializePool<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [AmmConfig::SEED],
+        bump = config.bump,
+        has_one = authority @ AmmError::Unauthorized
+    )]
+    pub config: Box<Account<'info, AmmConfig>>,
+
+    pub mint_a: Box<Account<'info, Mint>>,
+
+    #[account(
+        constraint = mint_a.key() < mint_b.key()
+            && mint_a.decimals == TOKEN_DECIMALS
+            && mint_b.decimals == TOKEN_DECIMALS
+            @ AmmError::InvalidPoolMints
+    )]
+    pub mint_b: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Pool::INIT_SPACE,
+        seeds = [Pool::SEED, mint_a.key().as_ref(), mint_b.key().as_ref()],
+        bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [Pool::VAULT_SEED, pool.key().as_ref(), mint_a.key().as_ref()],
+        bump,
+        token::mint = mint_a,
+        token::authority = pool
+    )]
+    pub vault_a: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [Pool::VAULT_SEED, pool.key().as_ref(), mint_b.key().as_ref()],
+        bump,
+        token::mint = mint_b,
+        token::authority = pool
+    )]
+    pub vault_b: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [Pool::LP_MINT_SEED, pool.key().as_ref()],
+        bump,
+        mint::decimals = TOKEN_DECIMALS,
+        mint::authority = pool
+    )]
+    pub lp_mint: Box<Account<'info, Mint>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<InitializePool>, amplification: u64, fee_bps: u16) -> Result<()> {
+    Pool::validate_params(amplification, fee_bps)?;
+
+    let pool = &mut ctx.accounts.pool;
+    pool.mint_a = ctx.accounts.mint_a.key();
+    pool.mint_b = ctx.accounts.mint_b.key();
+    pool.vault_a = ctx.accounts.vault_a.key();
+    pool.vault_b = ctx.accounts.vault_b.key();
+    pool.lp_mint = ctx.accounts.lp_mint.key();
+    pool.reserve_a = 0;
+    pool.reserve_b = 0;
+    pool.initial_amp = amplification;
+    pool.target_amp = amplification;
+    pool.ramp_start_ts = 0;
+    pool.ramp_stop_ts = 0;
+    pool.fee_bps = fee_bps;
+    pool.bump = ctx.bumps.pool;
+
+    emit!(PoolInitialized {
+        pool: pool.key(),
+        mint_a: pool.mint_a,
+        mint_b: pool.mint_b,
+        amplification,
+        fee_bps,
+    });
+    msg!(
+        "Pool {} created for {} and {} with A = {}",
+        pool.key(),
+        pool.mint_a,
+        pool.mint_b,
+        amplification
+    );
+    Ok(())
+}
@@ -0,0 +1,17 @@
+#![allow(ambiguous_glob_reexports)]
+
+pub mod add_liquidity;
+pub mod initialize_config;
+pub mod initialize_pool;
+pub mod ramp_a;
+pub mod remove_liquidity;
+pub mod stop_ramp_a;
+pub mod swap;
+
+pub use add_liquidity::*;
+pub use initialize_config::*;
+pub use initialize_pool::*;
+pub use ramp_a::*;
+pub use remove_liquidity::*;
+pub use stop_ramp_a::*;
+pub use swap::*;
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AmmError;
+use crate::events::AmpRampStarted;
+use crate::state::{AmmConfig, Pool};
+
+#[derive(Accounts)]
+pub struct RampA<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [AmmConfig::SEED],
+        bump = config.bump,
+        has_one = authority @ AmmError::Unauthorized
+    )]
+    pub config: Account<'info, AmmConfig>,
+
+    #[account(
+        mut,
+        seeds = [Pool::SEED, pool.mint_a.as_ref(), pool.mint_b.as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+}
+
+pub fn handler(ctx: Context<RampA>, target_amp: u64, stop_time: i64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let pool = &mut ctx.accounts.pool;
+    pool.start_ramp(target_amp, stop_time, now)?;
+
+    emit!(AmpRampStarted {
+        pool: pool.key(),
+        initial_amp: pool.initial_amp,
+        target_amp,
+        start_ts: now,
+        stop_ts: stop_time,
+    });
+    msg!(
+        "Ramping A from {} to {} by {}",
+        pool.initial_amp,
+        target_amp,
+        stop_time
+    );
+    Ok(())
+}
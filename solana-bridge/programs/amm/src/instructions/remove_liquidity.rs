@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+
+use crate::errors::AmmError;
+use crate::events::LiquidityRemoved;
+use crate::instructions::swap::pay_out;
+use crate::math;
+use crate::state::Pool;
+
+/// Burns LP tokens for a pro-rata share of both reserves. Needs no price, so
+/// it works however imbalanced the pool is.
+#[derive(Accounts)]
+pub struct RemoveLiquidity<'info> {
+    pub provider: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Pool::SEED, pool.mint_a.as_ref(), pool.mint_b.as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(mut, address = pool.vault_a)]
+    pub vault_a: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, address = pool.vault_b)]
+    pub vault_b: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, address = pool.lp_mint)]
+    pub lp_mint: Box<Account<'info, Mint>>,
+
+    #[account(mut, token::mint = lp_mint, token::authority = provider)]
+    pub lp_source: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, token::mint = pool.mint_a)]
+    pub destination_a: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, token::mint = pool.mint_b)]
+    pub destination_b: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<RemoveLiquidity>,
+    lp_amount: u64,
+    min_amount_a: u64,
+    min_amount_b: u64,
+) -> Result<()> {
+    let accounts = ctx.accounts;
+    let (amount_a, amount_b) = math::withdraw_amounts(
+        lp_amount,
+        accounts.lp_mint.supply,
+        accounts.pool.reserve_a,
+        accounts.pool.reserve_b,
+    )?;
+    require!(
+        amount_a >= min_amount_a && amount_b >= min_amount_b,
+        AmmError::SlippageExceeded
+    );
+
+    token::burn(
+        CpiContext::new(
+            accounts.token_program.to_account_info(),
+            Burn {
+                mint: accounts.lp_mint.to_account_info(),
+                from: accounts.lp_source.to_account_info(),
+                authority: accounts.provider.to_account_info(),
+            },
+        ),
+        lp_amount,
+    )?;
+    for (vault, destination, amount) in [
+        (&accounts.vault_a, &accounts.destination_a, amount_a),
+        (&accounts.vault_b, &accounts.destination_b, amount_b),
+    ] {
+        if amount > 0 {
+            pay_out(
+                &accounts.pool,
+                vault,
+                destination,
+                &accounts.token_program,
+                amount,
+            )?;
+        }
+    }
+
+    let pool = &mut accounts.pool;
+    pool.reserve_a -= amount_a;
+    pool.reserve_b -= amount_b;
+
+    emit!(LiquidityRemoved {
+        pool: pool.key(),
+        provider: accounts.provider.key(),
+        amount_a,
+        amount_b,
+        lp_burned: lp_amount,
+    });
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AmmError;
+use crate::events::AmpRampStopped;
+use crate::state::{AmmConfig, Pool};
+
+#[derive(Accounts)]
+pub struct StopRampA<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [AmmConfig::SEED],
+        bump = config.bump,
+        has_one = authority @ AmmError::Unauthorized
+    )]
+    pub config: Account<'info, AmmConfig>,
+
+    #[account(
+        mut,
+        seeds = [Pool::SEED, pool.mint_a.as_ref(), pool.mint_b.as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+}
+
+pub fn handler(ctx: Context<StopRampA>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let pool = &mut ctx.accounts.pool;
+    let amp = pool.stop_ramp(now);
+
+    emit!(AmpRampStopped {
+        pool: pool.key(),
+        amp,
+        stopped_at: now,
+    });
+    msg!("A held at {}", amp);
+    Ok(())
+}
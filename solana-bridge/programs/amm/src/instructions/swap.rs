@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::AmmError;
+use crate::events::Swapped;
+use crate::state::Pool;
+
+/// Sells `source`'s token for the pool's other token. Callable by CPI with a
+/// PDA as `user`; the amount received is the instruction's return value.
+#[derive(Accounts)]
+pub struct Swap<'info> {
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Pool::SEED, pool.mint_a.as_ref(), pool.mint_b.as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(mut, token::authority = user)]
+    pub source: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub destination: Account<'info, TokenAccount>,
+
+    #[account(mut, address = pool.vault_a)]
+    pub vault_a: Account<'info, TokenAccount>,
+
+    #[account(mut, address = pool.vault_b)]
+    pub vault_b: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<Swap>, amount_in: u64, min_amount_out: u64) -> Result<u64> {
+    require!(amount_in > 0, AmmError::ZeroAmount);
+    let accounts = ctx.accounts;
+    let a_to_b = accounts
+        .pool
+        .direction(&accounts.source.mint, &accounts.destination.mint)?;
+    let now = Clock::get()?.unix_timestamp;
+    let quote = accounts.pool.quote_swap(amount_in, a_to_b, now)?;
+    require!(
+        quote.amount_out >= min_amount_out,
+        AmmError::SlippageExceeded
+    );
+    require!(quote.amount_out > 0, AmmError::ZeroAmount);
+
+    let (vault_in, vault_out) = if a_to_b {
+        (&accounts.vault_a, &accounts.vault_b)
+    } else {
+        (&accounts.vault_b, &accounts.vault_a)
+    };
+    token::transfer(
+        CpiContext::new(
+            accounts.token_program.to_account_info(),
+            Transfer {
+                from: accounts.source.to_account_info(),
+                to: vault_in.to_account_info(),
+                authority: accounts.user.to_account_info(),
+            },
+        ),
+        amount_in,
+    )?;
+    pay_out(
+        &accounts.pool,
+        vault_out,
+        &accounts.destination,
+        &accounts.token_program,
+        quote.amount_out,
+    )?;
+    accounts
+        .pool
+        .apply_swap(amount_in, quote.amount_out, a_to_b)?;
+
+    emit!(Swapped {
+        pool: accounts.pool.key(),
+        user: accounts.user.key(),
+        mint_in: accounts.source.mint,
+        amount_in,
+        amount_out: quote.amount_out,
+        fee: quote.fee,
+    });
+    Ok(quote.amount_out)
+}
+
+/// Transfers `amount` out of one of the pool's vaults.
+pub fn pay_out<'info>(
+    pool: &Account<'info, Pool>,
+    vault: &Account<'info, TokenAccount>,
+    destination: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+    amount: u64,
+) -> Result<()> {
+    let seeds = pool.signer_seeds();
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            Transfer {
+                from: vault.to_account_info(),
+                to: destination.to_account_info(),
+                authority: pool.to_account_info(),
+            },
+            &[&seeds],
+        ),
+        amount,
+    )
+}
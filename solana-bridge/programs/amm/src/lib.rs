@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+pub mod errors;
+pub mod events;
+pub mod instructions;
+pub mod math;
+pub mod state;
+
+use instructions::*;
+
+declare_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[program]
+pub mod amm {
+    use super::*;
+
+    pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
+        instructions::initialize_config::handler(ctx)
+    }
+
+    pub fn initialize_pool(
+        ctx: Context<InitializePool>,
+        amplification: u64,
+        fee_bps: u16,
+    ) -> Result<()> {
+        instructions::initialize_pool::handler(ctx, amplification, fee_bps)
+    }
+
+    /// Returns the amount sent to `destination`.
+    pub fn swap(ctx: Context<Swap>, amount_in: u64, min_amount_out: u64) -> Result<u64> {
+        instructions::swap::handler(ctx, amount_in, min_amount_out)
+    }
+
+    pub fn add_liquidity(
+        ctx: Context<AddLiquidity>,
+        amount_a: u64,
+        amount_b: u64,
+        min_lp_out: u64,
+    ) -> Result<()> {
+        instructions::add_liquidity::handler(ctx, amount_a, amount_b, min_lp_out)
+    }
+
+    pub fn remove_liquidity(
+        ctx: Context<RemoveLiquidity>,
+        lp_amount: u64,
+        min_amount_a: u64,
+        min_amount_b: u64,
+    ) -> Result<()> {
+        instructions::remove_liquidity::handler(ctx, lp_amount, min_amount_a, min_amount_b)
+    }
+
+    pub fn ramp_a(ctx: Context<RampA>, target_amp: u64, stop_time: i64) -> Result<()> {
+        instructions::ramp_a::handler(ctx, target_amp, stop_time)
+    }
+
+    pub fn stop_ramp_a(ctx: Context<StopRampA>) -> Result<()> {
+        instructions::stop_ramp_a::handler(ctx)
+    }
+}
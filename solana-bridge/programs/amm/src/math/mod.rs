@@ -0,0 +1,8 @@
+//! StableSwap math. Depends only on `core`, so clients can quote with the
+//! same code the program swaps with.
+
+pub mod stable_swap;
+pub mod u256;
+
+pub use stable_swap::*;
+pub use u256::U256;
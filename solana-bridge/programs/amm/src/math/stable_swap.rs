@@ -0,0 +1,380 @@
+//! StableSwap invariant for two tokens, in 18-decimal fixed point.
+//!
+//! Follows Curve's two-coin pool: `A * n^n * sum(x) + D = A * D * n^n +
+//! D^(n+1) / (n^n * prod(x))`, solved for `D` or for one reserve by Newton's
+//! method. The EVM contracts must reproduce these functions bit for bit, and
+//! they are the only implementation here: the program swaps with them and
+//! clients link this module to quote. They use nothing beyond `core`.
+//!
+//! Rounding always favors the pool: amounts paid out and LP tokens minted
+//! round down, fees round up.
+
+use super::u256::U256;
+
+pub const N_COINS: u128 = 2;
+
+/// Fixed-point scale of balances and `D`.
+pub const PRECISION: u128 = 1_000_000_000_000_000_000;
+
+/// Decimals of the pooled tokens and of the LP token.
+pub const TOKEN_DECIMALS: u8 = 6;
+
+/// Converts a token amount into `PRECISION`.
+pub const RATE: u128 = PRECISION / 10u128.pow(TOKEN_DECIMALS as u32);
+
+/// Denominator of `fee_bps`.
+pub const FEE_DENOMINATOR: u128 = 10_000;
+
+/// Newton's method gives up after this many steps. Any pair of `u64`
+/// reserves converges in under 30.
+pub const MAX_ITERATIONS: usize = 64;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MathError {
+    Overflow,
+    InsufficientLiquidity,
+    ZeroAmount,
+    NotConverged,
+}
+
+pub type MathResult<T> = core::result::Result<T, MathError>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SwapCalculation {
+    /// Sent to the user, fee deducted.
+    pub amount_out: u64,
+    /// Kept by the pool, in the output token.
+    pub fee: u64,
+}
+
+/// Invariant `D` of a pool holding `reserve_a` and `reserve_b`, in
+/// `PRECISION`.
+pub fn get_d(reserve_a: u64, reserve_b: u64, amp: u64) -> MathResult<u128> {
+    compute_d(scale(reserve_a), scale(reserve_b), amp)
+}
+
+/// Other reserve, in `PRECISION`, that keeps the invariant at `invariant_d`
+/// when one reserve is `new_reserve_x`. Within one unit of the exact root.
+pub fn get_y(invariant_d: u128, new_reserve_x: u64, amp: u64) -> MathResult<u128> {
+    let x = U256::from(scale(new_reserve_x));
+    if x.is_zero() {
+        return Err(MathError::InsufficientLiquidity);
+    }
+    let d = U256::from(invariant_d);
+    let ann = ann(amp)?;
+    let n = U256::from(N_COINS);
+
+    let c = mul_div(mul_div(d, d, mul(x, n)?)?, d, mul(ann, n)?)?;
+    let b = add(x, div(d, ann)?)?;
+    let mut y = d;
+    for _ in 0..MAX_ITERATIONS {
+        let previous = y;
+        let denominator = sub(add(mul(y, n)?, b)?, d)?;
+        y = div(add(mul(y, y)?, c)?, denominator)?;
+        if converged(y, previous) {
+            return y.to_u128().ok_or(MathError::Overflow);
+        }
+    }
+    Err(MathError::NotConverged)
+}
+
+/// Output of selling `amount_in` into a pool holding `reserve_in` and
+/// `reserve_out`, after a fee of `fee_bps` of the output.
+pub fn calculate_swap(
+    amount_in: u64,
+    reserve_in: u64,
+    reserve_out: u64,
+    amp: u64,
+    fee_bps: u16,
+) -> MathResult<SwapCalculation> {
+    let d = get_d(reserve_in, reserve_out, amp)?;
+    let new_reserve_in = reserve_in
+        .checked_add(amount_in)
+        .ok_or(MathError::Overflow)?;
+    let y = get_y(d, new_reserve_in, amp)?;
+    // `y` may sit one unit below the root; one more keeps the pool whole.
+    let dy = scale(reserve_out).saturating_sub(y + 1) / RATE;
+    let fee = div_ceil(dy * fee_bps as u128, FEE_DENOMINATOR);
+    Ok(SwapCalculation {
+        amount_out: (dy - fee) as u64,
+        fee: fee as u64,
+    })
+}
+
+/// LP tokens minted for depositing `amounts` into a pool holding
+/// `reserves`.
+///
+/// Deposits that move the pool away from its current ratio pay half the swap
+/// fee on the imbalanced part, as a swap to the same position would.
+pub fn lp_tokens_to_mint(
+    reserves: (u64, u64),
+    amounts: (u64, u64),
+    lp_supply: u64,
+    amp: u64,
+    fee_bps: u16,
+) -> MathResult<u64> {
+    let old = [scale(reserves.0), scale(reserves.1)];
+    let new = [old[0] + scale(amounts.0), old[1] + scale(amounts.1)];
+    let d1 = compute_d(new[0], new[1], amp)?;
+    if lp_supply == 0 {
+        return to_u64(d1 / RATE);
+    }
+
+    let d0 = compute_d(old[0], old[1], amp)?;
+    if d1 <= d0 {
+        return Err(MathError::ZeroAmount);
+    }
+    let fee_bps = fee_bps as u128 * N_COINS / (4 * (N_COINS - 1));
+    let mut charged = new;
+    for (balance, old) in charged.iter_mut().zip(old) {
+        let ideal = mul_div(d1.into(), old.into(), d0.into())?
+            .to_u128()
+            .ok_or(MathError::Overflow)?;
+        let fee = div_ceil(ideal.abs_diff(*balance) * fee_bps, FEE_DENOMINATOR);
+        *balance = balance.checked_sub(fee).ok_or(MathError::Overflow)?;
+    }
+    let d2 = compute_d(charged[0], charged[1], amp)?;
+    let minted = mul_div(
+        U256::from(lp_supply),
+        U256::from(d2.saturating_sub(d0)),
+        d0.into(),
+    )?;
+    to_u64(minted.to_u128().ok_or(MathError::Overflow)?)
+}
+
+/// Share of each reserve paid for burning `lp_amount` of `lp_supply`.
+pub fn withdraw_amounts(
+    lp_amount: u64,
+    lp_supply: u64,
+    reserve_a: u64,
+    reserve_b: u64,
+) -> MathResult<(u64, u64)> {
+    if lp_amount == 0 {
+        return Err(MathError::ZeroAmount);
+    }
+    if lp_amount > lp_supply {
+        return Err(MathError::InsufficientLiquidity);
+    }
+    let share = |reserve: u64| (reserve as u128 * lp_amount as u128 / lp_supply as u128) as u64;
+    Ok((share(reserve_a), share(reserve_b)))
+}
+
+/// `D` of balances already in `PRECISION`.
+fn compute_d(x: u128, y: u128, amp: u64) -> MathResult<u128> {
+    let sum = add(x.into(), y.into())?;
+    if sum.is_zero() {
+        return Ok(0);
+    }
+    if x == 0 || y == 0 {
+        return Err(MathError::InsufficientLiquidity);
+    }
+    // Dividing by the smaller balance first keeps the rounding error in
+    // `d_p` small enough for Newton's method to settle.
+    let (low, high) = (U256::from(x.min(y)), U256::from(x.max(y)));
+    let ann = ann(amp)?;
+    let n = U256::from(N_COINS);
+
+    let mut d = sum;
+    for _ in 0..MAX_ITERATIONS {
+        let d_p = mul_div(mul_div(d, d, mul(low, n)?)?, d, mul(high, n)?)?;
+        let previous = d;
+        let numerator = mul(add(mul(ann, sum)?, mul(d_p, n)?)?, d)?;
+        let denominator = add(
+            mul(sub(ann, U256::from(1u128))?, d)?,
+            mul(d_p, U256::from(N_COINS + 1))?,
+        )?;
+        d = div(numerator, denominator)?;
+        if converged(d, previous) {
+            return d.to_u128().ok_or(MathError::Overflow);
+        }
+    }
+    Err(MathError::NotConverged)
+}
+
+fn scale(amount: u64) -> u128 {
+    amount as u128 * RATE
+}
+
+fn ann(amp: u64) -> MathResult<U256> {
+    if amp == 0 {
+        return Err(MathError::ZeroAmount);
+    }
+    Ok(U256::from(amp as u128 * N_COINS * N_COINS))
+}
+
+fn converged(value: U256, previous: U256) -> bool {
+    let diff = if value > previous {
+        value.checked_sub(previous)
+    } else {
+        previous.checked_sub(value)
+    };
+    diff.is_some_and(|diff| diff <= U256::from(1u128))
+}
+
+fn div_ceil(numerator: u128, denominator: u128) -> u128 {
+    numerator.div_ceil(denominator)
+}
+
+fn to_u64(value: u128) -> MathResult<u64> {
+    u64::try_from(value).map_err(|_| MathError::Overflow)
+}
+
+fn add(a: U256, b: U256) -> MathResult<U256> {
+    a.checked_add(b).ok_or(MathError::Overflow)
+}
+
+fn sub(a: U256, b: U256) -> MathResult<U256> {
+    a.checked_sub(b).ok_or(MathError::Overflow)
+}
+
+fn mul(a: U256, b: U256) -> MathResult<U256> {
+    a.checked_mul(b).ok_or(MathError::Overflow)
+}
+
+fn div(a: U256, b: U256) -> MathResult<U256> {
+    a.checked_div(b).ok_or(MathError::Overflow)
+}
+
+fn mul_div(a: U256, b: U256, c: U256) -> MathResult<U256> {
+    div(mul(a, b)?, c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RESERVE: u64 = 100_000_000_000;
+
+    #[test]
+    fn balanced_invariant_is_the_sum() {
+        // 100k of each, A = 100, from the v1 design.
+        assert_eq!(
+            get_d(RESERVE, RESERVE, 100),
+            Ok(200_000_000_000_000_000_000_000)
+        );
+        assert_eq!(get_d(0, 0, 100), Ok(0));
+        assert_eq!(
+            get_d(RESERVE, 0, 100),
+            Err(MathError::InsufficientLiquidity)
+        );
+        assert_eq!(get_d(RESERVE, RESERVE, 0), Err(MathError::ZeroAmount));
+    }
+
+    #[test]
+    fn get_y_inverts_get_d() {
+        let d = get_d(RESERVE, RESERVE / 3, 100).unwrap();
+        let y = get_y(d, RESERVE, 100).unwrap();
+        assert!(y.abs_diff(RESERVE as u128 / 3 * RATE) < RATE);
+    }
+
+    #[test]
+    fn converges_across_the_u64_range() {
+        let reserves = [
+            1_000_000,
+            1_000_000_000,
+            10u64.pow(13),
+            10u64.pow(18),
+            u64::MAX,
+        ];
+        for amp in [1, 10, 100, 10_000, 1_000_000] {
+            for a in reserves {
+                for b in reserves {
+                    let d = get_d(a, b, amp).unwrap();
+                    let y = get_y(d, a, amp).unwrap();
+                    // Within a millionth of a token unit of the true balance.
+                    assert!(
+                        y.abs_diff(b as u128 * RATE) < RATE / 1_000_000,
+                        "{amp} {a} {b}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn swap_matches_reference_pool() {
+        // 10k into a balanced 100k pool with A = 100. The v1 design quotes
+        // 9_997_523_100 to within 0.1%.
+        let quote = calculate_swap(10_000_000_000, RESERVE, RESERVE, 100, 0).unwrap();
+        assert_eq!(quote.amount_out, 9_994_977_677);
+        assert!(quote.amount_out.abs_diff(9_997_523_100) * 1_000 <= 9_997_523_100);
+        assert_eq!(quote.fee, 0);
+
+        let quote = calculate_swap(10_000_000_000, RESERVE, RESERVE, 100, 4).unwrap();
+        // 3_997_991.07 rounds up.
+        assert_eq!(quote.fee, 3_997_992);
+        assert_eq!(quote.amount_out + quote.fee, 9_994_977_677);
+    }
+
+    #[test]
+    fn rounding_favors_the_pool() {
+        // The fee on 2_501 units at 4 bps is 1.0004 units, charged as 2.
+        let quote = calculate_swap(2_502, RESERVE, RESERVE, 100, 4).unwrap();
+        assert_eq!(
+            quote,
+            SwapCalculation {
+                amount_out: 2_499,
+                fee: 2
+            }
+        );
+
+        // A swap too small to move the curve by a unit pays nothing out.
+        let dust = calculate_swap(1, RESERVE, RESERVE, 100, 0).unwrap();
+        assert_eq!(dust.amount_out, 0);
+
+        // Round trips never gain.
+        for amount in [1, 999, 1_000_000, 77_777_777_777] {
+            let there = calculate_swap(amount, RESERVE, RESERVE, 100, 0).unwrap();
+            let back = calculate_swap(
+                there.amount_out,
+                RESERVE - there.amount_out,
+                RESERVE + amount,
+                100,
+                0,
+            )
+            .unwrap();
+            assert!(back.amount_out < amount, "{amount}");
+        }
+    }
+
+    #[test]
+    fn higher_amplification_means_less_slippage() {
+        let low = calculate_swap(10_000_000_000, RESERVE, RESERVE, 10, 0).unwrap();
+        let high = calculate_swap(10_000_000_000, RESERVE, RESERVE, 1_000, 0).unwrap();
+        assert!(low.amount_out < high.amount_out);
+        assert!(high.amount_out < 10_000_000_000);
+    }
+
+    #[test]
+    fn balanced_deposit_mints_pro_rata() {
+        let supply = (get_d(RESERVE, RESERVE, 100).unwrap() / RATE) as u64;
+        let minted = lp_tokens_to_mint((RESERVE, RESERVE), (1_000, 1_000), supply, 100, 4);
+        assert_eq!(minted, Ok(2_000));
+
+        let first = lp_tokens_to_mint((0, 0), (1_000, 1_000), 0, 100, 4);
+        assert_eq!(first, Ok(2_000));
+        let nothing = lp_tokens_to_mint((RESERVE, RESERVE), (0, 0), supply, 100, 4);
+        assert_eq!(nothing, Err(MathError::ZeroAmount));
+    }
+
+    #[test]
+    fn imbalanced_deposit_pays_fee() {
+        let supply = (get_d(RESERVE, RESERVE, 100).unwrap() / RATE) as u64;
+        let amounts = (2_000_000_000, 0);
+        let free = lp_tokens_to_mint((RESERVE, RESERVE), amounts, supply, 100, 0).unwrap();
+        let charged = lp_tokens_to_mint((RESERVE, RESERVE), amounts, supply, 100, 4).unwrap();
+        assert!(charged < free);
+        assert!(free < 2_000_000_000);
+    }
+
+    #[test]
+    fn withdrawal_is_pro_rata_and_rounds_down() {
+        assert_eq!(withdraw_amounts(1, 3, 100, 200), Ok((33, 66)));
+        assert_eq!(withdraw_amounts(3, 3, 100, 200), Ok((100, 200)));
+        assert_eq!(
+            withdraw_amounts(4, 3, 100, 200),
+            Err(MathError::InsufficientLiquidity)
+        );
+        assert_eq!(withdraw_amounts(0, 3, 100, 200), Err(MathError::ZeroAmount));
+    }
+}
@@ -0,0 +1,210 @@
+//! Unsigned 256-bit integer with just the checked arithmetic the invariant
+//! needs. Products of two 18-decimal amounts overflow `u128`; keeping this
+//! local avoids a dependency and stays usable without `std`.
+
+use core::cmp::Ordering;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct U256 {
+    hi: u128,
+    lo: u128,
+}
+
+impl U256 {
+    pub const ZERO: Self = Self { hi: 0, lo: 0 };
+    pub const MAX: Self = Self {
+        hi: u128::MAX,
+        lo: u128::MAX,
+    };
+
+    pub const fn from_u128(value: u128) -> Self {
+        Self { hi: 0, lo: value }
+    }
+
+    pub fn to_u128(self) -> Option<u128> {
+        (self.hi == 0).then_some(self.lo)
+    }
+
+    pub fn is_zero(self) -> bool {
+        self == Self::ZERO
+    }
+
+    pub fn bits(self) -> u32 {
+        if self.hi != 0 {
+            256 - self.hi.leading_zeros()
+        } else {
+            128 - self.lo.leading_zeros()
+        }
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        let (lo, carry) = self.lo.overflowing_add(other.lo);
+        let hi = self.hi.checked_add(other.hi)?.checked_add(carry as u128)?;
+        Some(Self { hi, lo })
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        let (lo, borrow) = self.lo.overflowing_sub(other.lo);
+        let hi = self.hi.checked_sub(other.hi)?.checked_sub(borrow as u128)?;
+        Some(Self { hi, lo })
+    }
+
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        if self.hi != 0 && other.hi != 0 {
+            return None;
+        }
+        let low = full_mul(self.lo, other.lo);
+        let cross = self
+            .hi
+            .checked_mul(other.lo)?
+            .checked_add(self.lo.checked_mul(other.hi)?)?;
+        Some(Self {
+            hi: low.hi.checked_add(cross)?,
+            lo: low.lo,
+        })
+    }
+
+    /// Quotient rounded down, or `None` when dividing by zero.
+    pub fn checked_div(self, divisor: Self) -> Option<Self> {
+        if divisor.is_zero() {
+            return None;
+        }
+        if let (Some(n), Some(d)) = (self.to_u128(), divisor.to_u128()) {
+            return Some(Self::from_u128(n / d));
+        }
+        if self < divisor {
+            return Some(Self::ZERO);
+        }
+        // Shift-and-subtract, starting with the divisor aligned to the
+        // dividend's top bit.
+        let shift = self.bits() - divisor.bits();
+        let mut remainder = self;
+        let mut divisor = divisor.shl(shift);
+        let mut quotient = Self::ZERO;
+        for _ in 0..=shift {
+            quotient = quotient.shl(1);
+            if remainder >= divisor {
+                remainder = remainder.checked_sub(divisor)?;
+                quotient.lo |= 1;
+            }
+            divisor = divisor.shr1();
+        }
+        Some(quotient)
+    }
+
+    fn shl(self, shift: u32) -> Self {
+        match shift {
+            0 => self,
+            1..=127 => Self {
+                hi: (self.hi << shift) | (self.lo >> (128 - shift)),
+                lo: self.lo << shift,
+            },
+            128..=255 => Self {
+                hi: self.lo << (shift - 128),
+                lo: 0,
+            },
+            _ => Self::ZERO,
+        }
+    }
+
+    fn shr1(self) -> Self {
+        Self {
+            hi: self.hi >> 1,
+            lo: (self.lo >> 1) | (self.hi << 127),
+        }
+    }
+}
+
+impl From<u128> for U256 {
+    fn from(value: u128) -> Self {
+        Self::from_u128(value)
+    }
+}
+
+impl From<u64> for U256 {
+    fn from(value: u64) -> Self {
+        Self::from_u128(value as u128)
+    }
+}
+
+impl Ord for U256 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.hi, self.lo).cmp(&(other.hi, other.lo))
+    }
+}
+
+impl PartialOrd for U256 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Full 256-bit product of two `u128`s.
+fn full_mul(a: u128, b: u128) -> U256 {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_hi, a_lo) = (a >> 64, a & MASK);
+    let (b_hi, b_lo) = (b >> 64, b & MASK);
+
+    let lo_lo = a_lo * b_lo;
+    let hi_lo = a_hi * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_hi = a_hi * b_hi;
+
+    let middle = (lo_lo >> 64) + (hi_lo & MASK) + (lo_hi & MASK);
+    U256 {
+        hi: hi_hi + (hi_lo >> 64) + (lo_hi >> 64) + (middle >> 64),
+        lo: (middle << 64) | (lo_lo & MASK),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn u(value: u128) -> U256 {
+        U256::from(value)
+    }
+
+    #[test]
+    fn multiplies_past_u128() {
+        let product = u(u128::MAX).checked_mul(u(u128::MAX)).unwrap();
+        // (2^128 - 1)^2 = 2^256 - 2^129 + 1
+        assert_eq!(product.hi, u128::MAX - 1);
+        assert_eq!(product.lo, 1);
+        assert_eq!(product.checked_div(u(u128::MAX)), Some(u(u128::MAX)));
+
+        let shifted = u(1 << 100).checked_mul(u(1 << 100)).unwrap();
+        assert_eq!(shifted, U256 { hi: 1 << 72, lo: 0 });
+        assert_eq!(shifted.bits(), 201);
+        assert_eq!(U256::MAX.checked_mul(u(2)), None);
+    }
+
+    #[test]
+    fn divides_wide_values() {
+        let d = 200_000_000_000_000_000_000_000u128;
+        let d_squared = u(d).checked_mul(u(d)).unwrap();
+        assert_eq!(d_squared.checked_div(u(d)), Some(u(d)));
+        assert_eq!(d_squared.checked_div(u(2 * d)), Some(u(d / 2)));
+        // Rounds down.
+        assert_eq!(
+            d_squared.checked_add(u(d - 1)).unwrap().checked_div(u(d)),
+            Some(u(d))
+        );
+        assert_eq!(d_squared.checked_div(d_squared), Some(u(1)));
+        assert_eq!(u(5).checked_div(d_squared), Some(U256::ZERO));
+        assert_eq!(d_squared.checked_div(U256::ZERO), None);
+        assert_eq!(U256::MAX.checked_div(u(1)), Some(U256::MAX));
+    }
+
+    #[test]
+    fn add_and_sub_carry_between_halves() {
+        let max = u(u128::MAX);
+        let sum = max.checked_add(u(1)).unwrap();
+        assert_eq!(sum, U256 { hi: 1, lo: 0 });
+        assert_eq!(sum.checked_sub(u(1)), Some(max));
+        assert_eq!(u(1).checked_sub(u(2)), None);
+        assert_eq!(U256::MAX.checked_add(u(1)), None);
+        assert_eq!(sum.to_u128(), None);
+        assert!(sum > max);
+    }
+}
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+/// Program-wide settings. Its `authority` is the only signer that may create
+/// pools or ramp their amplification, so each pair's canonical pool cannot be
+/// claimed by whoever creates it first.
+#[account]
+#[derive(InitSpace)]
+pub struct AmmConfig {
+    pub authority: Pubkey,
+    pub bump: u8,
+}
+
+impl AmmConfig {
+    pub const SEED: &'static [u8] = b"amm_config";
+}
@@ -0,0 +1,5 @@
+pub mod amm_config;
+pub mod pool;
+
+pub use amm_config::*;
+pub use pool::*;
@@ -0,0 +1,290 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AmmError;
+use crate::math::{self, SwapCalculation};
+
+/// Highest swap fee a pool may charge (1%).
+pub const MAX_FEE_BPS: u16 = 100;
+
+/// Bounds of the amplification coefficient, as in Curve.
+pub const MIN_AMP: u64 = 1;
+pub const MAX_AMP: u64 = 1_000_000;
+
+/// Shortest ramp, and the least time between the start of two ramps.
+pub const MIN_RAMP_DURATION: i64 = 86_400;
+
+/// Largest factor one ramp may move A by, up or down.
+pub const MAX_AMP_CHANGE: u64 = 10;
+
+/// A StableSwap pool between two tokens of equal precision.
+///
+/// The pool PDA owns both vaults and is the LP mint authority. Reserves are
+/// tracked here rather than read from the vaults, so tokens sent to a vault
+/// directly do not move the price.
+#[account]
+#[derive(InitSpace)]
+pub struct Pool {
+    /// The lower of the two mints, so each pair has one pool.
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    pub vault_a: Pubkey,
+    pub vault_b: Pubkey,
+    pub lp_mint: Pubkey,
+    pub reserve_a: u64,
+    pub reserve_b: u64,
+    /// StableSwap amplification coefficient A when the last ramp started.
+    pub initial_amp: u64,
+    /// A the ramp reaches at `ramp_stop_ts` and holds from then on.
+    pub target_amp: u64,
+    pub ramp_start_ts: i64,
+    pub ramp_stop_ts: i64,
+    /// Swap fee, in basis points of the output.
+    pub fee_bps: u16,
+    pub bump: u8,
+}
+
+impl Pool {
+    pub const SEED: &'static [u8] = b"pool";
+    pub const VAULT_SEED: &'static [u8] = b"pool_vault";
+    pub const LP_MINT_SEED: &'static [u8] = b"lp_mint";
+
+    pub fn validate_params(amplification: u64, fee_bps: u16) -> Result<()> {
+        require!(
+            (MIN_AMP..=MAX_AMP).contains(&amplification),
+            AmmError::InvalidAmplification
+        );
+        require!(fee_bps <= MAX_FEE_BPS, AmmError::InvalidFee);
+        Ok(())
+    }
+
+    pub fn signer_seeds(&self) -> [&[u8]; 4] {
+        [
+            Self::SEED,
+            self.mint_a.as_ref(),
+            self.mint_b.as_ref(),
+            std::slice::from_ref(&self.bump),
+        ]
+    }
+
+    /// Effective A at `now`, moving linearly from `initial_amp` to
+    /// `target_amp` over the ramp.
+    pub fn current_amp(&self, now: i64) -> u64 {
+        if now >= self.ramp_stop_ts {
+            return self.target_amp;
+        }
+        let elapsed = now.saturating_sub(self.ramp_start_ts).max(0) as u128;
+        let duration = (self.ramp_stop_ts - self.ramp_start_ts) as u128;
+        let (initial, target) = (self.initial_amp as u128, self.target_amp as u128);
+        let amp = if target > initial {
+            initial + (target - initial) * elapsed / duration
+        } else {
+            initial - (initial - target) * elapsed / duration
+        };
+        amp as u64
+    }
+
+    /// Starts moving A from its current value to `target_amp`, reached at
+    /// `stop_ts`. Like Curve, a ramp must last a day, may change A by at most
+    /// `MAX_AMP_CHANGE` times, and can start at most once a day.
+    pub fn start_ramp(&mut self, target_amp: u64, stop_ts: i64, now: i64) -> Result<()> {
+        let unlocks_at = self.ramp_start_ts.saturating_add(MIN_RAMP_DURATION);
+        if now < unlocks_at {
+            msg!("Amplification can be ramped again at {}", unlocks_at);
+            return err!(AmmError::RampLocked);
+        }
+        require!(
+            (MIN_AMP..=MAX_AMP).contains(&target_amp),
+            AmmError::InvalidAmplification
+        );
+        require!(
+            stop_ts >= now.saturating_add(MIN_RAMP_DURATION),
+            AmmError::InvalidRamp
+        );
+        let current = self.current_amp(now);
+        let within_change = if target_amp >= current {
+            target_amp <= current * MAX_AMP_CHANGE
+        } else {
+            target_amp * MAX_AMP_CHANGE >= current
+        };
+        require!(within_change, AmmError::InvalidRamp);
+
+        self.initial_amp = current;
+        self.target_amp = target_amp;
+        self.ramp_start_ts = now;
+        self.ramp_stop_ts = stop_ts;
+        Ok(())
+    }
+
+    /// Holds A at its current value. Returns that value.
+    pub fn stop_ramp(&mut self, now: i64) -> u64 {
+        let current = self.current_amp(now);
+        self.initial_amp = current;
+        self.target_amp = current;
+        self.ramp_start_ts = now;
+        self.ramp_stop_ts = now;
+        current
+    }
+
+    /// True if `mint_in` is token A, i.e. the swap sells A for B.
+    pub fn direction(&self, mint_in: &Pubkey, mint_out: &Pubkey) -> Result<bool> {
+        if (mint_in, mint_out) == (&self.mint_a, &self.mint_b) {
+            Ok(true)
+        } else if (mint_in, mint_out) == (&self.mint_b, &self.mint_a) {
+            Ok(false)
+        } else {
+            err!(AmmError::InvalidTokenAccount)
+        }
+    }
+
+    /// Quotes a swap at the A in effect at `now`.
+    pub fn quote_swap(&self, amount_in: u64, a_to_b: bool, now: i64) -> Result<SwapCalculation> {
+        let (reserve_in, reserve_out) = if a_to_b {
+            (self.reserve_a, self.reserve_b)
+        } else {
+            (self.reserve_b, self.reserve_a)
+        };
+        Ok(math::calculate_swap(
+            amount_in,
+            reserve_in,
+            reserve_out,
+            self.current_amp(now),
+            self.fee_bps,
+        )?)
+    }
+
+    /// Books a swap quoted by [`Pool::quote_swap`]. The fee stays in the pool.
+    pub fn apply_swap(&mut self, amount_in: u64, amount_out: u64, a_to_b: bool) -> Result<()> {
+        let (reserve_in, reserve_out) = if a_to_b {
+            (&mut self.reserve_a, &mut self.reserve_b)
+        } else {
+            (&mut self.reserve_b, &mut self.reserve_a)
+        };
+        *reserve_in = reserve_in
+            .checked_add(amount_in)
+            .ok_or(AmmError::MathOverflow)?;
+        *reserve_out = reserve_out
+            .checked_sub(amount_out)
+            .ok_or(AmmError::InsufficientLiquidity)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_700_000_000;
+
+    fn pool() -> Pool {
+        Pool {
+            mint_a: Pubkey::new_unique(),
+            mint_b: Pubkey::new_unique(),
+            vault_a: Pubkey::new_unique(),
+            vault_b: Pubkey::new_unique(),
+            lp_mint: Pubkey::new_unique(),
+            reserve_a: 100_000_000_000,
+            reserve_b: 100_000_000_000,
+            initial_amp: 100,
+            target_amp: 100,
+            ramp_start_ts: 0,
+            ramp_stop_ts: 0,
+            fee_bps: 4,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn swap_keeps_fee_in_pool() {
+        let mut pool = pool();
+        let quote = pool.quote_swap(10_000_000_000, true, NOW).unwrap();
+        assert!(quote.fee > 0);
+        pool.apply_swap(10_000_000_000, quote.amount_out, true)
+            .unwrap();
+        assert_eq!(pool.reserve_a, 110_000_000_000);
+        assert_eq!(pool.reserve_b, 100_000_000_000 - quote.amount_out);
+
+        // Swapping back returns less than was put in.
+        let back = pool.quote_swap(quote.amount_out, false, NOW).unwrap();
+        assert!(back.amount_out < 10_000_000_000);
+    }
+
+    #[test]
+    fn direction_needs_both_pool_mints() {
+        let pool = pool();
+        assert!(pool.direction(&pool.mint_a, &pool.mint_b).unwrap());
+        assert!(!pool.direction(&pool.mint_b, &pool.mint_a).unwrap());
+        assert!(pool.direction(&pool.mint_a, &pool.mint_a).is_err());
+        assert!(pool.direction(&Pubkey::new_unique(), &pool.mint_b).is_err());
+    }
+
+    #[test]
+    fn ramp_interpolates_linearly() {
+        let mut pool = pool();
+        pool.start_ramp(200, NOW + 2 * MIN_RAMP_DURATION, NOW)
+            .unwrap();
+        assert_eq!(pool.current_amp(NOW - 1), 100);
+        assert_eq!(pool.current_amp(NOW), 100);
+        assert_eq!(pool.current_amp(NOW + MIN_RAMP_DURATION / 2), 125);
+        assert_eq!(pool.current_amp(NOW + MIN_RAMP_DURATION), 150);
+        assert_eq!(pool.current_amp(NOW + 2 * MIN_RAMP_DURATION), 200);
+        assert_eq!(pool.current_amp(i64::MAX), 200);
+
+        // Ramping down rounds towards the start value, like ramping up.
+        let later = NOW + 3 * MIN_RAMP_DURATION;
+        pool.start_ramp(30, later + 3 * MIN_RAMP_DURATION, later)
+            .unwrap();
+        assert_eq!(pool.current_amp(later + MIN_RAMP_DURATION), 144);
+        assert_eq!(pool.current_amp(later + 3 * MIN_RAMP_DURATION), 30);
+
+        // A swap quotes at the A in effect.
+        let early = pool.quote_swap(10_000_000_000, true, later).unwrap();
+        let late = pool
+            .quote_swap(10_000_000_000, true, later + 3 * MIN_RAMP_DURATION)
+            .unwrap();
+        assert!(late.amount_out < early.amount_out);
+    }
+
+    #[test]
+    fn ramp_is_rate_limited() {
+        let mut pool = pool();
+        let stop = NOW + MIN_RAMP_DURATION;
+        assert_eq!(
+            pool.start_ramp(1_001, stop, NOW).unwrap_err(),
+            AmmError::InvalidRamp.into()
+        );
+        assert_eq!(
+            pool.start_ramp(9, stop, NOW).unwrap_err(),
+            AmmError::InvalidRamp.into()
+        );
+        assert_eq!(
+            pool.start_ramp(200, stop - 1, NOW).unwrap_err(),
+            AmmError::InvalidRamp.into()
+        );
+        assert_eq!(
+            pool.start_ramp(0, stop, NOW).unwrap_err(),
+            AmmError::InvalidAmplification.into()
+        );
+        pool.start_ramp(1_000, stop, NOW).unwrap();
+
+        // Stopping freezes A where it is, and the next ramp still waits a day.
+        let halfway = NOW + MIN_RAMP_DURATION / 2;
+        assert_eq!(pool.stop_ramp(halfway), 550);
+        assert_eq!(pool.current_amp(stop), 550);
+        assert_eq!(
+            pool.start_ramp(100, halfway + MIN_RAMP_DURATION, halfway + 1)
+                .unwrap_err(),
+            AmmError::RampLocked.into()
+        );
+        let unlocked = halfway + MIN_RAMP_DURATION;
+        pool.start_ramp(100, unlocked + MIN_RAMP_DURATION, unlocked)
+            .unwrap();
+    }
+
+    #[test]
+    fn validates_params() {
+        assert!(Pool::validate_params(100, 4).is_ok());
+        assert!(Pool::validate_params(0, 4).is_err());
+        assert!(Pool::validate_params(MAX_AMP + 1, 4).is_err());
+        assert!(Pool::validate_params(100, MAX_FEE_BPS + 1).is_err());
+    }
+}
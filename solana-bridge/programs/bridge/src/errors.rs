@@ -0,0 +1,364 @@
+use anchor_lang::error::ERROR_CODE_OFFSET;
+use anchor_lang::prelude::*;
+
+/// Every failure the bridge program can report.
+///
+/// Codes are `6000 + discriminant` and are part of the public interface:
+/// relayers and the CLI match on them. Variants are grouped in blocks of 100
+/// by area; add new variants at the end of their block and never renumber.
+#[error_code]
+pub enum BridgeError {
+    // Configuration: 6000-6099
+    #[msg("Bridge fee exceeds the maximum allowed")]
+    InvalidFee = 0,
+    #[msg("Minimum transfer must be non-zero and not exceed the maximum transfer")]
+    InvalidTransferLimits,
+    #[msg("Daily volume cap must be at least the maximum transfer")]
+    InvalidDailyVolumeCap,
+    #[msg("Slippage tolerance exceeds the maximum allowed")]
+    InvalidSlippageTolerance,
+    #[msg("Wormhole program id must be set")]
+    InvalidWormholeProgram,
+    #[msg("Wormhole account data is invalid")]
+    InvalidWormholeAccount,
+    #[msg("Guardian set must hold 1 to 19 distinct, non-zero keys")]
+    InvalidGuardianSet,
+    #[msg("Guardian set index must be the next after the current set")]
+    InvalidGuardianSetIndex,
+    #[msg("Emitter chain or address is invalid")]
+    InvalidForeignEmitter,
+    #[msg("Token decimals are not supported")]
+    UnsupportedDecimals,
+    #[msg("Circuit breaker thresholds are invalid")]
+    InvalidCircuitBreakerConfig,
+    #[msg("Rate limit capacity and refill rate must be non-zero, refill at most capacity")]
+    InvalidRateLimit,
+    #[msg("Timelock delay is outside the allowed range")]
+    InvalidTimelockDelay,
+    #[msg("Release delay threshold must be non-zero and the delay within the allowed range")]
+    InvalidReleaseDelay,
+    #[msg("Price guard needs a band with 0 < min <= max and a positive max age")]
+    InvalidPriceGuard,
+
+    // Transfers: 6100-6199
+    #[msg("Transfer amount is below the minimum")]
+    AmountBelowMinimum = 100,
+    #[msg("Transfer amount is above the maximum")]
+    AmountAboveMaximum,
+    #[msg("Token is disabled for bridging")]
+    TokenDisabled,
+    #[msg("Transfer status change is not allowed")]
+    InvalidTransferTransition,
+    #[msg("Recipient is not a valid address on the destination chain")]
+    InvalidRecipient,
+    #[msg("Token account does not belong to the transfer recipient")]
+    RecipientMismatch,
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+    #[msg("Destination token must be a valid token address on the destination chain")]
+    InvalidDestinationToken,
+    #[msg("Recipient token account is not of the transfer's destination token")]
+    DestinationTokenMismatch,
+
+    // Transfer message codec: 6200-6299
+    #[msg("Transfer message version is not supported")]
+    UnsupportedMessageVersion = 200,
+    #[msg("Transfer message ended before all fields were read")]
+    TruncatedMessage,
+    #[msg("Transfer message has unexpected trailing bytes")]
+    TrailingMessageBytes,
+    #[msg("Foreign address must be 20 or 32 bytes")]
+    InvalidAddressLength,
+    #[msg("Transfer message has an invalid source chain")]
+    InvalidSourceChain,
+
+    // VAA verification: 6300-6399
+    #[msg("VAA is empty")]
+    EmptyVaa = 300,
+    #[msg("VAA ended before all fields were read")]
+    TruncatedVaa,
+    #[msg("VAA version is not supported")]
+    UnsupportedVaaVersion,
+    #[msg("VAA was signed by a different guardian set")]
+    GuardianSetMismatch,
+    #[msg("Guardian set has expired")]
+    GuardianSetExpired,
+    #[msg("VAA signatures must be in strictly ascending guardian order")]
+    SignaturesNotAscending,
+    #[msg("VAA signature references a guardian outside the set")]
+    GuardianIndexOutOfRange,
+    #[msg("VAA signature does not match its guardian")]
+    InvalidGuardianSignature,
+    #[msg("VAA does not carry a guardian quorum")]
+    InsufficientGuardianSignatures,
+    #[msg("VAA was not emitted by the registered bridge contract")]
+    UnknownEmitter,
+    #[msg("VAA has already been processed")]
+    AlreadyProcessed,
+
+    // Liquidity and pricing: 6400-6499
+    #[msg("Vault balance is insufficient for this release")]
+    InsufficientVaultBalance = 400,
+    #[msg("Output is below the minimum accepted amount")]
+    SlippageExceeded,
+    #[msg("Amount exceeds the fees accrued for this token")]
+    InsufficientAccruedFees,
+    #[msg("Price feed account is missing or not a valid price account")]
+    InvalidPriceFeed,
+    #[msg("Price feed is stale or not trading")]
+    StalePrice,
+    #[msg("Token price is outside the accepted band")]
+    PriceOutOfBand,
+    #[msg("Whirlpool accounts are invalid or do not pair the vault and recipient mints")]
+    InvalidWhirlpool,
+    #[msg("Delayed releases settle in the vault mint and cannot be swapped")]
+    SwapNotAllowed,
+    #[msg("StableSwap pool account is invalid or does not pair the requested mints")]
+    InvalidStablePool,
+
+    // Safety switches: 6500-6599
+    #[msg("Bridge is paused")]
+    BridgePaused = 500,
+    #[msg("Circuit breaker has tripped; withdrawals are paused")]
+    CircuitBreakerTripped,
+    #[msg("Circuit breaker is not tripped")]
+    CircuitBreakerNotTripped,
+    #[msg("Rate limit exceeded; see the log for when to retry")]
+    RateLimitExceeded,
+    #[msg("Transfers of this token are paused in this direction")]
+    TokenPaused,
+    #[msg("Transfers with this chain are paused in this direction")]
+    ChainPaused,
+    #[msg("Withdrawal is above the delay threshold and needs a pending release account")]
+    PendingReleaseRequired,
+    #[msg("Pending release is still locked; see the log for when it unlocks")]
+    ReleaseLocked,
+    #[msg("Pending release has been frozen by a watcher")]
+    ReleaseFrozen,
+    #[msg("Pending release is not frozen")]
+    ReleaseNotFrozen,
+    #[msg("Pending release account passed for a withdrawal below the delay threshold")]
+    PendingReleaseNotNeeded,
+
+    // Access control: 6600-6699
+    #[msg("Signer is not authorized for this action")]
+    Unauthorized = 600,
+
+    // Governance: 6700-6799
+    #[msg("Timelocked operation is not ready yet; see the log for when it is")]
+    TimelockNotReady = 700,
+    #[msg("Council needs 1 to 16 distinct members and a threshold between 1 and their number")]
+    InvalidCouncil,
+    #[msg("Signer is not a council member")]
+    NotCouncilMember,
+    #[msg("Member has already approved this proposal")]
+    AlreadyApproved,
+    #[msg("Proposal does not have enough approvals")]
+    InsufficientApprovals,
+    #[msg("Proposal has already been executed")]
+    ProposalClosed,
+    #[msg("Proposal was created before the last council change")]
+    ProposalStale,
+    #[msg("Accounts passed do not match the proposal")]
+    ProposalAccountMismatch,
+    #[msg("Proposal instruction is too large")]
+    ProposalTooLarge,
+}
+
+impl BridgeError {
+    /// Every variant, in code order. Keep in sync with the enum.
+    pub const ALL: &'static [BridgeError] = &[
+        BridgeError::InvalidFee,
+        BridgeError::InvalidTransferLimits,
+        BridgeError::InvalidDailyVolumeCap,
+        BridgeError::InvalidSlippageTolerance,
+        BridgeError::InvalidWormholeProgram,
+        BridgeError::InvalidWormholeAccount,
+        BridgeError::InvalidGuardianSet,
+        BridgeError::InvalidGuardianSetIndex,
+        BridgeError::InvalidForeignEmitter,
+        BridgeError::UnsupportedDecimals,
+        BridgeError::InvalidCircuitBreakerConfig,
+        BridgeError::InvalidRateLimit,
+        BridgeError::InvalidTimelockDelay,
+        BridgeError::InvalidReleaseDelay,
+        BridgeError::InvalidPriceGuard,
+        BridgeError::AmountBelowMinimum,
+        BridgeError::AmountAboveMaximum,
+        BridgeError::TokenDisabled,
+        BridgeError::InvalidTransferTransition,
+        BridgeError::InvalidRecipient,
+        BridgeError::RecipientMismatch,
+        BridgeError::MathOverflow,
+        BridgeError::InvalidDestinationToken,
+        BridgeError::DestinationTokenMismatch,
+        BridgeError::UnsupportedMessageVersion,
+        BridgeError::TruncatedMessage,
+        BridgeError::TrailingMessageBytes,
+        BridgeError::InvalidAddressLength,
+        BridgeError::InvalidSourceChain,
+        BridgeError::EmptyVaa,
+        BridgeError::TruncatedVaa,
+        BridgeError::UnsupportedVaaVersion,
+        BridgeError::GuardianSetMismatch,
+        BridgeError::GuardianSetExpired,
+        BridgeError::SignaturesNotAscending,
+        BridgeError::GuardianIndexOutOfRange,
+        BridgeError::InvalidGuardianSignature,
+        BridgeError::InsufficientGuardianSignatures,
+        BridgeError::UnknownEmitter,
+        BridgeError::AlreadyProcessed,
+        BridgeError::InsufficientVaultBalance,
+        BridgeError::SlippageExceeded,
+        BridgeError::InsufficientAccruedFees,
+        BridgeError::InvalidPriceFeed,
+        BridgeError::StalePrice,
+        BridgeError::PriceOutOfBand,
+        BridgeError::InvalidWhirlpool,
+        BridgeError::SwapNotAllowed,
+        BridgeError::InvalidStablePool,
+        BridgeError::BridgePaused,
+        BridgeError::CircuitBreakerTripped,
+        BridgeError::CircuitBreakerNotTripped,
+        BridgeError::RateLimitExceeded,
+        BridgeError::TokenPaused,
+        BridgeError::ChainPaused,
+        BridgeError::PendingReleaseRequired,
+        BridgeError::ReleaseLocked,
+        BridgeError::ReleaseFrozen,
+        BridgeError::ReleaseNotFrozen,
+        BridgeError::PendingReleaseNotNeeded,
+        BridgeError::Unauthorized,
+        BridgeError::TimelockNotReady,
+        BridgeError::InvalidCouncil,
+        BridgeError::NotCouncilMember,
+        BridgeError::AlreadyApproved,
+        BridgeError::InsufficientApprovals,
+        BridgeError::ProposalClosed,
+        BridgeError::ProposalStale,
+        BridgeError::ProposalAccountMismatch,
+        BridgeError::ProposalTooLarge,
+    ];
+
+    /// Numeric code reported on-chain, e.g. `6000` for `InvalidFee`.
+    pub fn code(self) -> u32 {
+        self.into()
+    }
+
+    /// Maps a raw custom program error code back to its variant.
+    pub fn from_code(code: u32) -> Option<Self> {
+        code.checked_sub(ERROR_CODE_OFFSET)?;
+        Self::ALL.iter().copied().find(|e| e.code() == code)
+    }
+
+    /// Extracts the variant from a client-side error string such as
+    /// `custom program error: 0x1771` or an Anchor log line containing
+    /// `Error Number: 6001`.
+    pub fn from_error_message(message: &str) -> Option<Self> {
+        const HEX_MARKER: &str = "custom program error: 0x";
+        const DEC_MARKER: &str = "Error Number: ";
+
+        let code = if let Some(start) = message.find(HEX_MARKER) {
+            let digits = leading(&message[start + HEX_MARKER.len()..], |c| {
+                c.is_ascii_hexdigit()
+            });
+            u32::from_str_radix(digits, 16).ok()?
+        } else if let Some(start) = message.find(DEC_MARKER) {
+            let digits = leading(&message[start + DEC_MARKER.len()..], |c| c.is_ascii_digit());
+            digits.parse().ok()?
+        } else {
+            return None;
+        };
+        Self::from_code(code)
+    }
+}
+
+fn leading(s: &str, pred: impl Fn(char) -> bool) -> &str {
+    let end = s.find(|c: char| !pred(c)).unwrap_or(s.len());
+    &s[..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use amm::errors::AmmError;
+
+    #[test]
+    fn codes_are_pinned() {
+        assert_eq!(BridgeError::InvalidFee.code(), 6000);
+        assert_eq!(BridgeError::AmountBelowMinimum.code(), 6100);
+        assert_eq!(BridgeError::UnsupportedMessageVersion.code(), 6200);
+        assert_eq!(BridgeError::EmptyVaa.code(), 6300);
+        assert_eq!(BridgeError::AlreadyProcessed.code(), 6310);
+        assert_eq!(BridgeError::InsufficientVaultBalance.code(), 6400);
+        assert_eq!(BridgeError::BridgePaused.code(), 6500);
+        assert_eq!(BridgeError::Unauthorized.code(), 6600);
+        assert_eq!(BridgeError::TimelockNotReady.code(), 6700);
+    }
+
+    #[test]
+    fn all_is_ordered_and_round_trips() {
+        // Codes run without gaps inside a block, and each block starts on a
+        // multiple of 100.
+        assert_eq!(BridgeError::ALL[0].code(), 6000);
+        for pair in BridgeError::ALL.windows(2) {
+            let (code, next) = (pair[0].code(), pair[1].code());
+            assert!(
+                next == code + 1 || (next % 100 == 0 && next / 100 > code / 100),
+                "{:?}",
+                pair
+            );
+        }
+        for error in BridgeError::ALL {
+            let parsed = BridgeError::from_code(error.code()).map(|e| e.code());
+            assert_eq!(parsed, Some(error.code()));
+        }
+        assert!(BridgeError::from_code(0).is_none());
+        assert!(BridgeError::from_code(6099).is_none());
+    }
+
+    #[test]
+    fn all_lists_every_variant() {
+        let source = include_str!("errors.rs");
+        let start = source.find("pub enum BridgeError {").unwrap();
+        let end = source.find("impl BridgeError {").unwrap();
+        let declared: Vec<&str> = source[start..end]
+            .lines()
+            .skip(1)
+            .map(str::trim)
+            .filter(|line| line.starts_with(|c: char| c.is_ascii_uppercase()))
+            .map(|line| line.split([' ', ',']).next().unwrap())
+            .collect();
+        let listed: Vec<String> = BridgeError::ALL.iter().map(|e| e.name()).collect();
+        assert_eq!(listed, declared);
+    }
+
+    #[test]
+    fn ignores_amm_errors() {
+        for error in [AmmError::InvalidAmplification, AmmError::SlippageExceeded] {
+            let code = u32::from(error);
+            assert!(BridgeError::from_code(code).is_none(), "{code}");
+            let message = format!("custom program error: {code:#x}");
+            assert!(BridgeError::from_error_message(&message).is_none());
+        }
+    }
+
+    #[test]
+    fn parses_client_error_strings() {
+        let parsed = BridgeError::from_error_message(
+            "Error processing Instruction 0: custom program error: 0x189e",
+        );
+        assert_eq!(parsed.map(|e| e.code()), Some(6302));
+
+        let parsed = BridgeError::from_error_message(
+            "AnchorError occurred. Error Code: AlreadyProcessed. Error Number: 6310.",
+        );
+        assert_eq!(
+            parsed.map(|e| e.name()),
+            Some("AlreadyProcessed".to_string())
+        );
+
+        assert!(BridgeError::from_error_message("custom program error: 0x0").is_none());
+        assert!(BridgeError::from_error_message("insufficient funds").is_none());
+    }
+}
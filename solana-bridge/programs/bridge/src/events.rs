@@ -0,0 +1,184 @@
+use anchor_lang::prelude::*;
+
+use crate::security::{PauseFlags, PauseScope, Role, TimelockAction, TripReason};
+
+#[event]
+pub struct CircuitBreakerTripped {
+    pub reason: TripReason,
+    pub mint: Pubkey,
+    /// USD released for `mint` over the rolling window.
+    pub window_outflow: u64,
+    /// USD released for `mint` in the current hour.
+    pub current_outflow: u64,
+    pub tripped_at: i64,
+}
+
+#[event]
+pub struct CircuitBreakerReset {
+    pub admin: Pubkey,
+    pub reason: TripReason,
+    pub mint: Pubkey,
+    pub reset_at: i64,
+}
+
+#[event]
+pub struct PauseUpdated {
+    pub scope: PauseScope,
+    pub previous: PauseFlags,
+    pub paused: PauseFlags,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct RoleGranted {
+    pub role: Role,
+    pub member: Pubkey,
+    pub granted_by: Pubkey,
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub role: Role,
+    pub member: Pubkey,
+    /// The member itself when the role was renounced.
+    pub revoked_by: Pubkey,
+}
+
+#[event]
+pub struct FeeUpdated {
+    pub previous_bps: u16,
+    pub fee_bps: u16,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct AdminProposed {
+    pub admin: Pubkey,
+    /// `Pubkey::default()` when a pending proposal is withdrawn.
+    pub pending_admin: Pubkey,
+}
+
+#[event]
+pub struct AdminTransferred {
+    pub previous: Pubkey,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct OperationScheduled {
+    pub id: [u8; 32],
+    pub action: TimelockAction,
+    pub proposer: Pubkey,
+    pub eta: i64,
+}
+
+#[event]
+pub struct OperationCancelled {
+    pub id: [u8; 32],
+    pub cancelled_by: Pubkey,
+}
+
+#[event]
+pub struct OperationExecuted {
+    pub id: [u8; 32],
+    pub executed_by: Pubkey,
+}
+
+#[event]
+pub struct CouncilUpdated {
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub epoch: u32,
+}
+
+#[event]
+pub struct ProposalCreated {
+    pub id: u64,
+    pub proposer: Pubkey,
+}
+
+#[event]
+pub struct ProposalApproved {
+    pub id: u64,
+    pub member: Pubkey,
+    pub approvals: u32,
+}
+
+#[event]
+pub struct ProposalExecuted {
+    pub id: u64,
+    pub executor: Pubkey,
+}
+
+#[event]
+pub struct ProposalCancelled {
+    pub id: u64,
+}
+
+#[event]
+pub struct FeesWithdrawn {
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub destination: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct ReleaseQueued {
+    pub transfer_id: [u8; 32],
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub unlock_at: i64,
+}
+
+#[event]
+pub struct ReleaseVetoed {
+    pub transfer_id: [u8; 32],
+    pub watcher: Pubkey,
+}
+
+#[event]
+pub struct ReleaseResolved {
+    pub transfer_id: [u8; 32],
+    pub approved: bool,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct ReleaseFinalized {
+    pub transfer_id: [u8; 32],
+    pub amount: u64,
+    pub destination: Pubkey,
+}
+
+#[event]
+pub struct SolvencyViolated {
+    pub mint: Pubkey,
+    pub vault_balance: u64,
+    /// Balance the ledger expects; zero if more went out than came in.
+    pub expected_balance: u64,
+    pub in_flight: u64,
+}
+
+#[event]
+pub struct VaultFunded {
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct TransferRefundable {
+    pub transfer_id: [u8; 32],
+    /// USD value the transfer would have settled for.
+    pub settled_usd: u64,
+    pub min_destination_amount: u64,
+}
+
+#[event]
+pub struct TransferRefunded {
+    pub transfer_id: [u8; 32],
+    /// Id of the transfer that carries the funds back.
+    pub refund_id: [u8; 32],
+    pub sequence: u64,
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::AdminTransferred;
+use crate::state::BridgeConfig;
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    pub pending_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BridgeConfig::SEED],
+        bump = config.bump,
+        has_one = pending_admin @ BridgeError::Unauthorized
+    )]
+    pub config: Account<'info, BridgeConfig>,
+}
+
+pub fn handler(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let previous = config.admin;
+    config.admin = config.pending_admin;
+    config.pending_admin = Pubkey::default();
+
+    emit!(AdminTransferred {
+        previous,
+        admin: config.admin,
+    });
+    msg!("Admin transferred from {} to {}", previous, config.admin);
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+
+use crate::errors::BridgeError;
+use crate::security::{
+    has_role, OutflowWindow, PauseFlags, PriceGuard, Role, RoleMember, OUTFLOW_BUCKET_SECONDS,
+};
+use crate::state::{BridgeConfig, SupportedToken, SwapPools, TokenLedger, MAX_TOKEN_DECIMALS};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct TokenParams {
+    pub min_amount_usd: u64,
+    pub max_amount_usd: u64,
+}
+
+impl TokenParams {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.min_amount_usd > 0 && self.min_amount_usd <= self.max_amount_usd,
+            BridgeError::InvalidTransferLimits
+        );
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct AddToken<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = config.bump,
+        constraint = has_role(&config, &authority.key(), role_member.as_deref(), Role::Operator)
+            @ BridgeError::Unauthorized
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [RoleMember::SEED, &[role_member.role as u8], authority.key().as_ref()],
+        bump = role_member.bump
+    )]
+    pub role_member: Option<Account<'info, RoleMember>>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + SupportedToken::INIT_SPACE,
+        seeds = [SupportedToken::SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub supported_token: Account<'info, SupportedToken>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + OutflowWindow::INIT_SPACE,
+        seeds = [OutflowWindow::SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub outflow_window: Account<'info, OutflowWindow>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + TokenLedger::INIT_SPACE,
+        seeds = [TokenLedger::SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub ledger: Account<'info, TokenLedger>,
+
+    #[account(
+        seeds = [BridgeConfig::VAULT_SEED, mint.key().as_ref()],
+        bump,
+        token::mint = mint
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AddToken>, params: TokenParams) -> Result<()> {
+    params.validate()?;
+    require!(
+        ctx.accounts.mint.decimals <= MAX_TOKEN_DECIMALS,
+        BridgeError::UnsupportedDecimals
+    );
+
+    let token = &mut ctx.accounts.supported_token;
+    token.mint = ctx.accounts.mint.key();
+    token.decimals = ctx.accounts.mint.decimals;
+    token.min_amount_usd = params.min_amount_usd;
+    token.max_amount_usd = params.max_amount_usd;
+    token.enabled = true;
+    token.paused = PauseFlags::default();
+    token.price_guard = PriceGuard::default();
+    token.swap_pools = SwapPools::default();
+    token.vault_bump = ctx.bumps.vault;
+    token.bump = ctx.bumps.supported_token;
+
+    let window = &mut ctx.accounts.outflow_window;
+    window.mint = ctx.accounts.mint.key();
+    window.current_hour = Clock::get()?
+        .unix_timestamp
+        .div_euclid(OUTFLOW_BUCKET_SECONDS);
+    window.bump = ctx.bumps.outflow_window;
+
+    let ledger = &mut ctx.accounts.ledger;
+    ledger.mint = ctx.accounts.mint.key();
+    ledger.bump = ctx.bumps.ledger;
+
+    msg!(
+        "Token {} added with {} decimals",
+        token.mint,
+        token.decimals
+    );
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::events::ProposalApproved;
+use crate::state::{Council, Proposal};
+
+#[derive(Accounts)]
+pub struct Approve<'info> {
+    pub member: Signer<'info>,
+
+    #[account(seeds = [Council::SEED], bump = council.bump)]
+    pub council: Account<'info, Council>,
+
+    #[account(
+        mut,
+        seeds = [Proposal::SEED, &proposal.id.to_be_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
+
+pub fn handler(ctx: Context<Approve>) -> Result<()> {
+    let council = &ctx.accounts.council;
+    let member = ctx.accounts.member.key();
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.check_open(council)?;
+    proposal.approve(council.member_index(&member)?)?;
+
+    emit!(ProposalApproved {
+        id: proposal.id,
+        member,
+        approvals: proposal.approval_count(),
+    });
+    msg!(
+        "Proposal {} approved by {} ({} of {})",
+        proposal.id,
+        member,
+        proposal.approval_count(),
+        council.threshold
+    );
+    Ok(())
+}
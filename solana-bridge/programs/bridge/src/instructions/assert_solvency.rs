@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+
+use crate::events::{CircuitBreakerTripped, SolvencyViolated};
+use crate::security::{OutflowWindow, TripReason};
+use crate::state::{BridgeConfig, SupportedToken, TokenLedger};
+
+/// Permissionless check that a vault holds what its ledger says.
+#[derive(Accounts)]
+pub struct AssertSolvency<'info> {
+    #[account(mut, seeds = [BridgeConfig::SEED], bump = config.bump)]
+    pub config: Account<'info, BridgeConfig>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        seeds = [SupportedToken::SEED, mint.key().as_ref()],
+        bump = supported_token.bump
+    )]
+    pub supported_token: Account<'info, SupportedToken>,
+
+    #[account(
+        seeds = [TokenLedger::SEED, mint.key().as_ref()],
+        bump = ledger.bump
+    )]
+    pub ledger: Account<'info, TokenLedger>,
+
+    #[account(
+        seeds = [OutflowWindow::SEED, mint.key().as_ref()],
+        bump = outflow_window.bump
+    )]
+    pub outflow_window: Account<'info, OutflowWindow>,
+
+    #[account(
+        seeds = [BridgeConfig::VAULT_SEED, mint.key().as_ref()],
+        bump = supported_token.vault_bump,
+        token::mint = mint
+    )]
+    pub vault: Account<'info, TokenAccount>,
+}
+
+/// Trips the circuit breaker if the vault falls short. It succeeds either
+/// way so that the trip is kept.
+pub fn handler(ctx: Context<AssertSolvency>) -> Result<()> {
+    let ledger = &ctx.accounts.ledger;
+    let vault_balance = ctx.accounts.vault.amount;
+    let expected_balance = ledger.expected_balance().unwrap_or_default();
+
+    if ledger.is_solvent(vault_balance) {
+        msg!(
+            "Vault holds {}, ledger expects {}",
+            vault_balance,
+            expected_balance
+        );
+        return Ok(());
+    }
+
+    emit!(SolvencyViolated {
+        mint: ledger.mint,
+        vault_balance,
+        expected_balance,
+        in_flight: ledger.in_flight,
+    });
+    msg!(
+        "Solvency violated: vault holds {}, ledger expects {}",
+        vault_balance,
+        expected_balance
+    );
+
+    let breaker = &mut ctx.accounts.config.circuit_breaker;
+    if !breaker.is_tripped() {
+        let now = Clock::get()?.unix_timestamp;
+        let window = &ctx.accounts.outflow_window;
+        breaker.trip(TripReason::LedgerMismatch, ledger.mint, now);
+        emit!(CircuitBreakerTripped {
+            reason: TripReason::LedgerMismatch,
+            mint: ledger.mint,
+            window_outflow: u64::try_from(window.total()).unwrap_or(u64::MAX),
+            current_outflow: window.current(),
+            tripped_at: now,
+        });
+    }
+    Ok(())
+}
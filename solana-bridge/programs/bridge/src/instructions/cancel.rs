@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::ProposalCancelled;
+use crate::state::Proposal;
+
+#[derive(Accounts)]
+pub struct Cancel<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [Proposal::SEED, &proposal.id.to_be_bytes()],
+        bump = proposal.bump,
+        has_one = proposer @ BridgeError::Unauthorized,
+        constraint = !proposal.executed @ BridgeError::ProposalClosed
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
+
+pub fn handler(ctx: Context<Cancel>) -> Result<()> {
+    emit!(ProposalCancelled {
+        id: ctx.accounts.proposal.id,
+    });
+    msg!("Proposal {} cancelled", ctx.accounts.proposal.id);
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::OperationCancelled;
+use crate::security::{has_role_or_admin, RoleMember, TimelockOperation};
+use crate::state::BridgeConfig;
+
+#[derive(Accounts)]
+pub struct CancelOperation<'info> {
+    pub authority: Signer<'info>,
+
+    /// Holders of the action's role or of `Admin` may cancel.
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = config.bump,
+        constraint = has_role_or_admin(
+            &config,
+            &authority.key(),
+            role_member.as_deref(),
+            operation.action.role()
+        ) @ BridgeError::Unauthorized
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [RoleMember::SEED, &[role_member.role as u8], authority.key().as_ref()],
+        bump = role_member.bump
+    )]
+    pub role_member: Option<Account<'info, RoleMember>>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [TimelockOperation::SEED, &operation.id],
+        bump = operation.bump
+    )]
+    pub operation: Account<'info, TimelockOperation>,
+
+    /// CHECK: Receives the operation's rent back.
+    #[account(mut, address = operation.proposer)]
+    pub proposer: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CancelOperation>) -> Result<()> {
+    emit!(OperationCancelled {
+        id: ctx.accounts.operation.id,
+        cancelled_by: ctx.accounts.authority.key(),
+    });
+    msg!("Cancelled {:?}", ctx.accounts.operation.action);
+    Ok(())
+}
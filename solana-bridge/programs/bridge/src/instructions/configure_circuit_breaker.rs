@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::security::{has_role, CircuitBreakerParams, Role, RoleMember};
+use crate::state::BridgeConfig;
+
+#[derive(Accounts)]
+pub struct ConfigureCircuitBreaker<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BridgeConfig::SEED],
+        bump = config.bump,
+        constraint = has_role(&config, &authority.key(), role_member.as_deref(), Role::Admin)
+            @ BridgeError::Unauthorized
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [RoleMember::SEED, &[role_member.role as u8], authority.key().as_ref()],
+        bump = role_member.bump
+    )]
+    pub role_member: Option<Account<'info, RoleMember>>,
+}
+
+pub fn handler(ctx: Context<ConfigureCircuitBreaker>, params: CircuitBreakerParams) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    params.validate(config.max_transfer)?;
+    config.circuit_breaker.configure(&params);
+
+    msg!(
+        "Circuit breaker set: window cap {}, spike {}x above {}",
+        params.window_outflow_cap,
+        params.spike_multiplier,
+        params.spike_floor
+    );
+    Ok(())
+}
@@ -0,0 +1,283 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::errors::BridgeError;
+use crate::integrations::wormhole::{
+    self, Finality, PostMessage, BRIDGE_SEED, FEE_COLLECTOR_SEED, SEQUENCE_SEED,
+};
+use crate::message::TransferMessage;
+use crate::security::{self, RateLimit};
+use crate::state::{
+    BridgeConfig, ForeignEmitter, SupportedToken, TokenLedger, TransferDirection, TransferRecord,
+    TransferStatus, WormholeEmitter, ETHEREUM_CHAIN_ID, SOLANA_CHAIN_ID,
+};
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    #[account(mut, seeds = [BridgeConfig::SEED], bump = config.bump)]
+    pub config: Box<Account<'info, BridgeConfig>>,
+
+    #[account(
+        init,
+        payer = depositor,
+        space = 8 + TransferRecord::INIT_SPACE,
+        seeds = [
+            TransferRecord::SEED,
+            &TransferRecord::outbound_id(config.transfer_nonce, &depositor.key()),
+        ],
+        bump
+    )]
+    pub transfer_record: Box<Account<'info, TransferRecord>>,
+
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        seeds = [SupportedToken::SEED, mint.key().as_ref()],
+        bump = supported_token.bump
+    )]
+    pub supported_token: Box<Account<'info, SupportedToken>>,
+
+    /// CHECK: Price account named by the token's price guard; parsed by the
+    /// guard. Only needed when the guard is enabled.
+    #[account(address = supported_token.price_guard.feed)]
+    pub price_feed: Option<UncheckedAccount<'info>>,
+
+    /// Registry entry of the destination chain; carries its pause flags.
+    #[account(
+        seeds = [ForeignEmitter::SEED, &ETHEREUM_CHAIN_ID.to_be_bytes()],
+        bump = foreign_emitter.bump
+    )]
+    pub foreign_emitter: Box<Account<'info, ForeignEmitter>>,
+
+    #[account(
+        mut,
+        seeds = [
+            RateLimit::SEED,
+            &ETHEREUM_CHAIN_ID.to_be_bytes(),
+            mint.key().as_ref(),
+            &[TransferDirection::Outbound as u8],
+        ],
+        bump = rate_limit.bump
+    )]
+    pub rate_limit: Box<Account<'info, RateLimit>>,
+
+    #[account(
+        mut,
+        seeds = [TokenLedger::SEED, mint.key().as_ref()],
+        bump = ledger.bump
+    )]
+    pub ledger: Box<Account<'info, TokenLedger>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = depositor
+    )]
+    pub depositor_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [BridgeConfig::VAULT_SEED, mint.key().as_ref()],
+        bump = supported_token.vault_bump,
+        token::mint = mint,
+        token::authority = vault_authority
+    )]
+    pub vault: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Data-less PDA that owns the vault token accounts.
+    #[account(
+        seeds = [BridgeConfig::VAULT_AUTHORITY_SEED],
+        bump = config.vault_authority_bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(mut, seeds = [WormholeEmitter::SEED], bump = wormhole_emitter.bump)]
+    pub wormhole_emitter: Box<Account<'info, WormholeEmitter>>,
+
+    /// CHECK: Core bridge config, validated by the Wormhole program.
+    #[account(mut, seeds = [BRIDGE_SEED], bump, seeds::program = wormhole_program.key())]
+    pub wormhole_bridge: UncheckedAccount<'info>,
+
+    /// CHECK: Core bridge fee collector, validated by the Wormhole program.
+    #[account(
+        mut,
+        seeds = [FEE_COLLECTOR_SEED],
+        bump,
+        seeds::program = wormhole_program.key()
+    )]
+    pub wormhole_fee_collector: UncheckedAccount<'info>,
+
+    /// CHECK: Sequence tracker of our emitter, owned by the Wormhole program.
+    #[account(
+        mut,
+        seeds = [SEQUENCE_SEED, wormhole_emitter.key().as_ref()],
+        bump,
+        seeds::program = wormhole_program.key()
+    )]
+    pub wormhole_sequence: UncheckedAccount<'info>,
+
+    /// CHECK: Created by the Wormhole program to hold the posted message.
+    #[account(
+        mut,
+        seeds = [
+            WormholeEmitter::MESSAGE_SEED,
+            &wormhole_emitter.sequence.to_be_bytes(),
+        ],
+        bump
+    )]
+    pub wormhole_message: UncheckedAccount<'info>,
+
+    /// CHECK: Must be the Wormhole core bridge named in the config.
+    #[account(executable, address = config.wormhole_program)]
+    pub wormhole_program: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// `min_amount_out` is the destination floor: the least, in tokens of this
+/// mint, the recipient accepts on the other chain. Nothing is swapped on this
+/// side, so it is only converted to USD and carried in the message; the
+/// destination refunds rather than settle below it. `destination_token` is
+/// the left-padded token contract the recipient is paid in.
+pub fn handler(
+    ctx: Context<Deposit>,
+    amount: u64,
+    min_amount_out: u64,
+    recipient: [u8; 32],
+    destination_token: [u8; 32],
+) -> Result<()> {
+    require!(
+        is_evm_address(&destination_token),
+        BridgeError::InvalidDestinationToken
+    );
+    let token = &ctx.accounts.supported_token;
+    token.check_enabled()?;
+    security::check_not_paused(
+        TransferDirection::Outbound,
+        &ctx.accounts.config.paused,
+        &token.paused,
+        &ctx.accounts.foreign_emitter.paused,
+    )?;
+
+    let config = &mut ctx.accounts.config;
+    let gross_usd = token.to_usd(amount)?;
+    config.check_transfer_amount(gross_usd)?;
+    token.check_amount_usd(gross_usd)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    token
+        .price_guard
+        .check(ctx.accounts.price_feed.as_deref(), now)?;
+    ctx.accounts.rate_limit.bucket.consume(gross_usd, now)?;
+    config.daily_volume.consume(gross_usd, now)?;
+
+    let fee = config.fee_for(amount)?;
+    let net_amount = amount.checked_sub(fee).ok_or(BridgeError::MathOverflow)?;
+    let amount_usd = token.to_usd(net_amount)?;
+    let min_destination_amount = token.to_usd(min_amount_out)?;
+
+    let nonce = config.transfer_nonce;
+    config.transfer_nonce = nonce.checked_add(1).ok_or(BridgeError::MathOverflow)?;
+
+    // Lock the full amount; the fee stays in the vault.
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.depositor_token_account.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+                authority: ctx.accounts.depositor.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    ctx.accounts.ledger.record_deposit(amount, fee)?;
+
+    let transfer_id = TransferRecord::outbound_id(nonce, &ctx.accounts.depositor.key());
+    let payload = TransferMessage {
+        version: TransferMessage::VERSION,
+        transfer_id,
+        sender: ctx.accounts.depositor.key().to_bytes().to_vec(),
+        recipient: evm_recipient(&recipient)?.to_vec(),
+        destination_token,
+        amount_usd,
+        min_destination_amount,
+        nonce,
+        source_chain: SOLANA_CHAIN_ID,
+        timestamp: now,
+    }
+    .encode()?;
+
+    let emitter = &mut ctx.accounts.wormhole_emitter;
+    let sequence = emitter.sequence;
+    let sequence_bytes = sequence.to_be_bytes();
+    let emitter_seeds: &[&[u8]] = &[WormholeEmitter::SEED, &[emitter.bump]];
+    let message_seeds: &[&[u8]] = &[
+        WormholeEmitter::MESSAGE_SEED,
+        &sequence_bytes,
+        &[ctx.bumps.wormhole_message],
+    ];
+    wormhole::post_message(
+        PostMessage {
+            wormhole_program: &ctx.accounts.wormhole_program.to_account_info(),
+            bridge: &ctx.accounts.wormhole_bridge.to_account_info(),
+            message: &ctx.accounts.wormhole_message.to_account_info(),
+            emitter: &emitter.to_account_info(),
+            sequence: &ctx.accounts.wormhole_sequence.to_account_info(),
+            payer: &ctx.accounts.depositor.to_account_info(),
+            fee_collector: &ctx.accounts.wormhole_fee_collector.to_account_info(),
+            clock: &ctx.accounts.clock.to_account_info(),
+            rent: &ctx.accounts.rent.to_account_info(),
+            system_program: &ctx.accounts.system_program.to_account_info(),
+        },
+        0,
+        payload,
+        Finality::Finalized,
+        &[emitter_seeds, message_seeds],
+    )?;
+    emitter.sequence = sequence.checked_add(1).ok_or(BridgeError::MathOverflow)?;
+
+    let record = &mut ctx.accounts.transfer_record;
+    record.transfer_id = transfer_id;
+    record.direction = TransferDirection::Outbound;
+    record.status = TransferStatus::Initiated;
+    record.mint = ctx.accounts.mint.key();
+    record.local_account = ctx.accounts.depositor.key();
+    record.counterparty = recipient;
+    record.amount = amount;
+    record.fee = fee;
+    record.amount_usd = amount_usd;
+    record.source_chain = SOLANA_CHAIN_ID;
+    record.destination_chain = ETHEREUM_CHAIN_ID;
+    record.sequence = sequence;
+    record.created_at = now;
+    record.updated_at = now;
+    record.bump = ctx.bumps.transfer_record;
+
+    msg!(
+        "Deposit of {} (fee {}) from {}, nonce {}, sequence {}",
+        amount,
+        fee,
+        record.local_account,
+        nonce,
+        sequence
+    );
+    Ok(())
+}
+
+/// Ethereum recipients arrive left-padded to 32 bytes; the message carries
+/// the bare 20-byte address.
+fn evm_recipient(recipient: &[u8; 32]) -> Result<[u8; 20]> {
+    require!(is_evm_address(recipient), BridgeError::InvalidRecipient);
+    Ok(recipient[12..].try_into().unwrap())
+}
+
+fn is_evm_address(address: &[u8; 32]) -> bool {
+    address[..12].iter().all(|b| *b == 0) && address[12..] != [0u8; 20]
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+
+use crate::errors::BridgeError;
+use crate::events::ProposalExecuted;
+use crate::program::Bridge;
+use crate::state::{Council, Proposal};
+
+/// The proposal's accounts follow as remaining accounts, in order.
+#[derive(Accounts)]
+pub struct Execute<'info> {
+    pub executor: Signer<'info>,
+
+    #[account(seeds = [Council::SEED], bump = council.bump)]
+    pub council: Account<'info, Council>,
+
+    #[account(
+        mut,
+        seeds = [Proposal::SEED, &proposal.id.to_be_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    pub bridge_program: Program<'info, Bridge>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, Execute<'info>>) -> Result<()> {
+    let council = &ctx.accounts.council;
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.check_open(council)?;
+    require!(
+        proposal.approval_count() >= council.threshold as u32,
+        BridgeError::InsufficientApprovals
+    );
+    let instruction = proposal.instruction(ctx.remaining_accounts)?;
+
+    // Persist before the call so the proposal cannot be executed again from
+    // within it.
+    proposal.executed = true;
+    proposal.exit(&crate::ID)?;
+
+    let mut infos = ctx.remaining_accounts.to_vec();
+    infos.push(ctx.accounts.bridge_program.to_account_info());
+    invoke_signed(&instruction, &infos, &[&council.authority_seeds()])?;
+
+    emit!(ProposalExecuted {
+        id: proposal.id,
+        executor: ctx.accounts.executor.key(),
+    });
+    msg!("Proposal {} executed", proposal.id);
+    Ok(())
+}
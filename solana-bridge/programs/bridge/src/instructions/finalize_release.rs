@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::events::ReleaseFinalized;
+use crate::instructions::withdraw::release_from_vault;
+use crate::security::{self, OutflowWindow};
+use crate::state::{
+    BridgeConfig, ForeignEmitter, PendingRelease, SupportedToken, TokenLedger,
+    TransferDirection, TransferRecord, TransferStatus,
+};
+
+/// Permissionless: anyone can pay out an unlocked, unfrozen release.
+#[derive(Accounts)]
+pub struct FinalizeRelease<'info> {
+    pub cranker: Signer<'info>,
+
+    #[account(mut, seeds = [BridgeConfig::SEED], bump = config.bump)]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        close = payer,
+        seeds = [PendingRelease::SEED, &pending_release.transfer_id],
+        bump = pending_release.bump
+    )]
+    pub pending_release: Account<'info, PendingRelease>,
+
+    #[account(
+        mut,
+        seeds = [TransferRecord::SEED, &pending_release.transfer_id],
+        bump = transfer_record.bump
+    )]
+    pub transfer_record: Account<'info, TransferRecord>,
+
+    #[account(address = pending_release.mint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        seeds = [SupportedToken::SEED, mint.key().as_ref()],
+        bump = supported_token.bump
+    )]
+    pub supported_token: Account<'info, SupportedToken>,
+
+    /// CHECK: Price account named by the token's price guard; parsed by the
+    /// guard. Only needed when the guard is enabled.
+    #[account(address = supported_token.price_guard.feed)]
+    pub price_feed: Option<UncheckedAccount<'info>>,
+
+    /// Registry entry of the source chain; carries its pause flags.
+    #[account(
+        seeds = [ForeignEmitter::SEED, &transfer_record.source_chain.to_be_bytes()],
+        bump = foreign_emitter.bump
+    )]
+    pub foreign_emitter: Account<'info, ForeignEmitter>,
+
+    #[account(
+        mut,
+        seeds = [OutflowWindow::SEED, mint.key().as_ref()],
+        bump = outflow_window.bump
+    )]
+    pub outflow_window: Account<'info, OutflowWindow>,
+
+    #[account(
+        mut,
+        seeds = [TokenLedger::SEED, mint.key().as_ref()],
+        bump = ledger.bump
+    )]
+    pub ledger: Account<'info, TokenLedger>,
+
+    #[account(mut, address = pending_release.destination)]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [BridgeConfig::VAULT_SEED, mint.key().as_ref()],
+        bump = supported_token.vault_bump,
+        token::mint = mint,
+        token::authority = vault_authority
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// CHECK: Data-less PDA that owns the vault token accounts.
+    #[account(
+        seeds = [BridgeConfig::VAULT_AUTHORITY_SEED],
+        bump = config.vault_authority_bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Receives the pending release's rent back.
+    #[account(mut, address = pending_release.payer)]
+    pub payer: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<FinalizeRelease>) -> Result<()> {
+    ctx.accounts.config.circuit_breaker.check_closed()?;
+    security::check_not_paused(
+        TransferDirection::Inbound,
+        &ctx.accounts.config.paused,
+        &ctx.accounts.supported_token.paused,
+        &ctx.accounts.foreign_emitter.paused,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts
+        .supported_token
+        .price_guard
+        .check(ctx.accounts.price_feed.as_deref(), now)?;
+    let pending = &ctx.accounts.pending_release;
+    pending.check_finalizable(now)?;
+
+    release_from_vault(
+        &ctx.accounts.config,
+        &ctx.accounts.vault,
+        &ctx.accounts.recipient_token_account,
+        &ctx.accounts.vault_authority,
+        &ctx.accounts.token_program,
+        pending.amount,
+    )?;
+    ctx.accounts
+        .transfer_record
+        .transition(TransferStatus::Completed, now)?;
+    ctx.accounts.ledger.release_held(pending.amount)?;
+    ctx.accounts.config.circuit_breaker.record_outflow(
+        &mut ctx.accounts.outflow_window,
+        pending.amount_usd,
+        now,
+    )?;
+
+    emit!(ReleaseFinalized {
+        transfer_id: pending.transfer_id,
+        amount: pending.amount,
+        destination: pending.destination,
+    });
+    msg!("Released {} to {}", pending.amount, pending.destination);
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::errors::BridgeError;
+use crate::events::VaultFunded;
+use crate::security::{has_role, Role, RoleMember};
+use crate::state::{BridgeConfig, SupportedToken, TokenLedger};
+
+/// Adds liquidity to a vault so inbound transfers can be paid before
+/// deposits have built it up. Tokens sent to the vault any other way are
+/// not counted by its ledger.
+#[derive(Accounts)]
+pub struct FundVault<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = config.bump,
+        constraint = has_role(&config, &authority.key(), role_member.as_deref(), Role::Operator)
+            @ BridgeError::Unauthorized
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [RoleMember::SEED, &[role_member.role as u8], authority.key().as_ref()],
+        bump = role_member.bump
+    )]
+    pub role_member: Option<Account<'info, RoleMember>>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        seeds = [SupportedToken::SEED, mint.key().as_ref()],
+        bump = supported_token.bump
+    )]
+    pub supported_token: Account<'info, SupportedToken>,
+
+    #[account(
+        mut,
+        seeds = [TokenLedger::SEED, mint.key().as_ref()],
+        bump = ledger.bump
+    )]
+    pub ledger: Account<'info, TokenLedger>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = authority
+    )]
+    pub source: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [BridgeConfig::VAULT_SEED, mint.key().as_ref()],
+        bump = supported_token.vault_bump,
+        token::mint = mint
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<FundVault>, amount: u64) -> Result<()> {
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.source.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    ctx.accounts.ledger.record_funding(amount)?;
+
+    emit!(VaultFunded {
+        mint: ctx.accounts.mint.key(),
+        amount,
+        authority: ctx.accounts.authority.key(),
+    });
+    msg!(
+        "Vault of {} funded with {}",
+        ctx.accounts.mint.key(),
+        amount
+    );
+    Ok(())
+}
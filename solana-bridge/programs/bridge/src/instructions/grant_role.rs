@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::RoleGranted;
+use crate::security::{can_manage_role, Role, RoleMember};
+use crate::state::BridgeConfig;
+
+#[derive(Accounts)]
+#[instruction(role: Role, member: Pubkey)]
+pub struct GrantRole<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = config.bump,
+        constraint = can_manage_role(&config, &authority.key(), role_member.as_deref(), role)
+            @ BridgeError::Unauthorized
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [RoleMember::SEED, &[role_member.role as u8], authority.key().as_ref()],
+        bump = role_member.bump
+    )]
+    pub role_member: Option<Account<'info, RoleMember>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + RoleMember::INIT_SPACE,
+        seeds = [RoleMember::SEED, &[role as u8], member.as_ref()],
+        bump
+    )]
+    pub grant: Account<'info, RoleMember>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<GrantRole>, role: Role, member: Pubkey) -> Result<()> {
+    let grant = &mut ctx.accounts.grant;
+    grant.role = role;
+    grant.member = member;
+    grant.granted_by = ctx.accounts.authority.key();
+    grant.granted_at = Clock::get()?.unix_timestamp;
+    grant.bump = ctx.bumps.grant;
+
+    emit!(RoleGranted {
+        role,
+        member,
+        granted_by: grant.granted_by,
+    });
+    msg!("Granted {:?} to {}", role, member);
+    Ok(())
+}
@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::security::{CircuitBreaker, PauseFlags, TimelockOperation, TokenBucket};
+use crate::state::{BridgeConfig, WormholeEmitter, MAX_FEE_BPS, MAX_SLIPPAGE_BPS};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct InitializeBridgeParams {
+    pub wormhole_program: Pubkey,
+    pub fee_bps: u16,
+    pub min_transfer: u64,
+    pub max_transfer: u64,
+    pub daily_volume_cap: u64,
+    pub max_slippage_bps: u16,
+    pub timelock_delay: i64,
+    pub release_delay_threshold: u64,
+    pub release_delay: i64,
+}
+
+impl InitializeBridgeParams {
+    pub fn validate(&self) -> Result<()> {
+        require_keys_neq!(
+            self.wormhole_program,
+            Pubkey::default(),
+            BridgeError::InvalidWormholeProgram
+        );
+        require!(self.fee_bps <= MAX_FEE_BPS, BridgeError::InvalidFee);
+        BridgeConfig::validate_transfer_limits(
+            self.min_transfer,
+            self.max_transfer,
+            self.daily_volume_cap,
+        )?;
+        require!(
+            self.max_slippage_bps <= MAX_SLIPPAGE_BPS,
+            BridgeError::InvalidSlippageTolerance
+        );
+        TimelockOperation::check_delay(self.timelock_delay)?;
+        BridgeConfig::validate_release_delay(self.release_delay_threshold, self.release_delay)?;
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct InitializeBridge<'info> {
+    // `init` fails if the config already exists, so the bridge can only be
+    // initialized once.
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + BridgeConfig::INIT_SPACE,
+        seeds = [BridgeConfig::SEED],
+        bump
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    /// CHECK: Data-less PDA that owns the vault token accounts.
+    #[account(seeds = [BridgeConfig::VAULT_AUTHORITY_SEED], bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + WormholeEmitter::INIT_SPACE,
+        seeds = [WormholeEmitter::SEED],
+        bump
+    )]
+    pub wormhole_emitter: Account<'info, WormholeEmitter>,
+
+    /// Must be the program's upgrade authority, so nobody can front-run the
+    /// deployer and take the admin seat.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::Bridge>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key())
+            @ BridgeError::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeBridge>, params: InitializeBridgeParams) -> Result<()> {
+    params.validate()?;
+
+    let config = &mut ctx.accounts.config;
+    config.admin = ctx.accounts.admin.key();
+    config.pending_admin = Pubkey::default();
+    config.timelock_delay = params.timelock_delay;
+    config.wormhole_program = params.wormhole_program;
+    config.fee_bps = params.fee_bps;
+    config.min_transfer = params.min_transfer;
+    config.max_transfer = params.max_transfer;
+    config.daily_volume_cap = params.daily_volume_cap;
+    config.daily_volume = TokenBucket::daily(params.daily_volume_cap, Clock::get()?.unix_timestamp);
+    config.max_slippage_bps = params.max_slippage_bps;
+    config.circuit_breaker =
+        CircuitBreaker::initial(params.daily_volume_cap, params.max_transfer);
+    config.release_delay_threshold = params.release_delay_threshold;
+    config.release_delay = params.release_delay;
+    config.paused = PauseFlags::default();
+    config.vault_authority_bump = ctx.bumps.vault_authority;
+    config.bump = ctx.bumps.config;
+
+    let emitter = &mut ctx.accounts.wormhole_emitter;
+    emitter.sequence = 0;
+    emitter.bump = ctx.bumps.wormhole_emitter;
+
+    msg!("Bridge initialized by {}", config.admin);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params() -> InitializeBridgeParams {
+        InitializeBridgeParams {
+            wormhole_program: Pubkey::new_unique(),
+            fee_bps: 30,
+            min_transfer: 10_000_000,
+            max_transfer: 1_000_000_000_000,
+            daily_volume_cap: 10_000_000_000_000,
+            max_slippage_bps: 50,
+            timelock_delay: 2 * 86_400,
+            release_delay_threshold: 100_000_000_000,
+            release_delay: 3_600,
+        }
+    }
+
+    fn rejects(change: impl FnOnce(&mut InitializeBridgeParams), error: BridgeError) {
+        let mut params = params();
+        change(&mut params);
+        assert_eq!(params.validate().unwrap_err(), error.into(), "{error:?}");
+    }
+
+    #[test]
+    fn validates_params() {
+        assert!(params().validate().is_ok());
+        rejects(
+            |p| p.wormhole_program = Pubkey::default(),
+            BridgeError::InvalidWormholeProgram,
+        );
+        rejects(|p| p.fee_bps = MAX_FEE_BPS + 1, BridgeError::InvalidFee);
+        rejects(|p| p.min_transfer = 0, BridgeError::InvalidTransferLimits);
+        rejects(
+            |p| p.daily_volume_cap = p.max_transfer - 1,
+            BridgeError::InvalidDailyVolumeCap,
+        );
+        rejects(
+            |p| p.max_slippage_bps = MAX_SLIPPAGE_BPS + 1,
+            BridgeError::InvalidSlippageTolerance,
+        );
+        rejects(|p| p.timelock_delay = 0, BridgeError::InvalidTimelockDelay);
+        rejects(|p| p.release_delay = 0, BridgeError::InvalidReleaseDelay);
+    }
+}
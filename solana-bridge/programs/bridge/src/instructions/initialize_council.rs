@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::events::CouncilUpdated;
+use crate::state::{BridgeConfig, Council};
+
+#[derive(Accounts)]
+pub struct InitializeCouncil<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(seeds = [BridgeConfig::SEED], bump = config.bump, has_one = admin)]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + Council::INIT_SPACE,
+        seeds = [Council::SEED],
+        bump
+    )]
+    pub council: Account<'info, Council>,
+
+    /// CHECK: Data-less PDA that signs for the council. Propose it as admin
+    /// and have the council accept to hand over control.
+    #[account(seeds = [Council::AUTHORITY_SEED], bump)]
+    pub council_authority: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeCouncil>, members: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    Council::check_membership(&members, threshold)?;
+
+    let council = &mut ctx.accounts.council;
+    council.members = members;
+    council.threshold = threshold;
+    council.epoch = 0;
+    council.proposal_count = 0;
+    council.authority_bump = ctx.bumps.council_authority;
+    council.bump = ctx.bumps.council;
+
+    emit!(CouncilUpdated {
+        members: council.members.clone(),
+        threshold,
+        epoch: council.epoch,
+    });
+    msg!(
+        "Council created: {} of {}, authority {}",
+        threshold,
+        council.members.len(),
+        ctx.accounts.council_authority.key()
+    );
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::errors::BridgeError;
+use crate::security::{has_role, Role, RoleMember};
+use crate::state::BridgeConfig;
+
+#[derive(Accounts)]
+pub struct InitializeVault<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = config.bump,
+        constraint = has_role(&config, &authority.key(), role_member.as_deref(), Role::Operator)
+            @ BridgeError::Unauthorized
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [RoleMember::SEED, &[role_member.role as u8], authority.key().as_ref()],
+        bump = role_member.bump
+    )]
+    pub role_member: Option<Account<'info, RoleMember>>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [BridgeConfig::VAULT_SEED, mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = vault_authority
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// CHECK: Data-less PDA that owns the vault token accounts.
+    #[account(
+        seeds = [BridgeConfig::VAULT_AUTHORITY_SEED],
+        bump = config.vault_authority_bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<InitializeVault>) -> Result<()> {
+    msg!(
+        "Vault {} created for mint {}",
+        ctx.accounts.vault.key(),
+        ctx.accounts.mint.key()
+    );
+    Ok(())
+}
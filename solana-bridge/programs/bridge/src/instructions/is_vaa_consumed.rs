@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Claim;
+
+#[derive(Accounts)]
+#[instruction(emitter_chain: u16, emitter_address: [u8; 32], sequence: u64)]
+pub struct IsVaaConsumed<'info> {
+    /// CHECK: May not exist yet; only read when owned by this program.
+    #[account(
+        seeds = [
+            Claim::SEED,
+            &emitter_chain.to_be_bytes(),
+            &emitter_address,
+            &sequence.to_be_bytes(),
+        ],
+        bump
+    )]
+    pub claim: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<IsVaaConsumed>,
+    _emitter_chain: u16,
+    _emitter_address: [u8; 32],
+    _sequence: u64,
+) -> Result<bool> {
+    Claim::is_consumed(&ctx.accounts.claim)
+}
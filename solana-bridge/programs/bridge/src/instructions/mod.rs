@@ -1,7 +1,87 @@
+#![allow(ambiguous_glob_reexports)]
+
 pub mod initialize;
+pub mod initialize_vault;
+pub mod set_guardian_set;
+pub mod register_emitter;
+pub mod update_emitter;
+pub mod add_token;
+pub mod update_token;
+pub mod disable_token;
 pub mod deposit;
 pub mod withdraw;
+pub mod is_vaa_consumed;
+pub mod configure_circuit_breaker;
+pub mod reset_circuit_breaker;
+pub mod set_rate_limit;
+pub mod set_bridge_pause;
+pub mod set_token_pause;
+pub mod set_chain_pause;
+pub mod grant_role;
+pub mod revoke_role;
+pub mod renounce_role;
+pub mod set_fee;
+pub mod propose_admin;
+pub mod accept_admin;
+pub mod schedule_operation;
+pub mod cancel_operation;
+pub mod set_transfer_limits;
+pub mod withdraw_fees;
+pub mod initialize_council;
+pub mod set_council;
+pub mod propose;
+pub mod approve;
+pub mod execute;
+pub mod cancel;
+pub mod set_release_delay;
+pub mod veto_release;
+pub mod resolve_release;
+pub mod finalize_release;
+pub mod fund_vault;
+pub mod assert_solvency;
+pub mod set_price_guard;
+pub mod refund_transfer;
+pub mod set_swap_pools;
 
 pub use initialize::*;
+pub use initialize_vault::*;
+pub use set_guardian_set::*;
+pub use register_emitter::*;
+pub use update_emitter::*;
+pub use add_token::*;
+pub use update_token::*;
+pub use disable_token::*;
 pub use deposit::*;
 pub use withdraw::*;
+pub use is_vaa_consumed::*;
+pub use configure_circuit_breaker::*;
+pub use reset_circuit_breaker::*;
+pub use set_rate_limit::*;
+pub use set_bridge_pause::*;
+pub use set_token_pause::*;
+pub use set_chain_pause::*;
+pub use grant_role::*;
+pub use revoke_role::*;
+pub use renounce_role::*;
+pub use set_fee::*;
+pub use propose_admin::*;
+pub use accept_admin::*;
+pub use schedule_operation::*;
+pub use cancel_operation::*;
+pub use set_transfer_limits::*;
+pub use withdraw_fees::*;
+pub use initialize_council::*;
+pub use set_council::*;
+pub use propose::*;
+pub use approve::*;
+pub use execute::*;
+pub use cancel::*;
+pub use set_release_delay::*;
+pub use veto_release::*;
+pub use resolve_release::*;
+pub use finalize_release::*;
+pub use fund_vault::*;
+pub use assert_solvency::*;
+pub use set_price_guard::*;
+pub use refund_transfer::*;
+pub use set_swap_pools::*;
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::{ProposalApproved, ProposalCreated};
+use crate::state::{Council, Proposal, ProposalAccount};
+
+#[derive(Accounts)]
+pub struct Propose<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(mut, seeds = [Council::SEED], bump = council.bump)]
+    pub council: Account<'info, Council>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + Proposal::INIT_SPACE,
+        seeds = [Proposal::SEED, &council.proposal_count.to_be_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Proposes a call to this program signed by the council authority. The
+/// proposer's approval is counted.
+pub fn handler(ctx: Context<Propose>, accounts: Vec<ProposalAccount>, data: Vec<u8>) -> Result<()> {
+    Proposal::check_size(&accounts, &data)?;
+    let council = &mut ctx.accounts.council;
+    let proposer = ctx.accounts.proposer.key();
+    let member_index = council.member_index(&proposer)?;
+
+    let id = council.proposal_count;
+    council.proposal_count = id.checked_add(1).ok_or(BridgeError::MathOverflow)?;
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.id = id;
+    proposal.epoch = council.epoch;
+    proposal.proposer = proposer;
+    proposal.accounts = accounts;
+    proposal.data = data;
+    proposal.approvals = 0;
+    proposal.approve(member_index)?;
+    proposal.executed = false;
+    proposal.created_at = Clock::get()?.unix_timestamp;
+    proposal.bump = ctx.bumps.proposal;
+
+    emit!(ProposalCreated { id, proposer });
+    emit!(ProposalApproved {
+        id,
+        member: proposer,
+        approvals: proposal.approval_count(),
+    });
+    msg!("Proposal {} created by {}", id, proposer);
+    Ok(())
+}
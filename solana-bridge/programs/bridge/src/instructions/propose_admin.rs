@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::events::AdminProposed;
+use crate::state::BridgeConfig;
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(mut, seeds = [BridgeConfig::SEED], bump = config.bump, has_one = admin)]
+    pub config: Account<'info, BridgeConfig>,
+}
+
+/// Nominates the next owner. Proposing `Pubkey::default()` withdraws a
+/// pending nomination.
+pub fn handler(ctx: Context<ProposeAdmin>, pending_admin: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.pending_admin = pending_admin;
+
+    emit!(AdminProposed {
+        admin: config.admin,
+        pending_admin,
+    });
+    msg!("Admin transfer to {} proposed", pending_admin);
+    Ok(())
+}
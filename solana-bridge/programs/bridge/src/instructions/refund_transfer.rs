@@ -0,0 +1,203 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::TransferRefunded;
+use crate::integrations::wormhole::{
+    self, Finality, PostMessage, BRIDGE_SEED, FEE_COLLECTOR_SEED, SEQUENCE_SEED,
+};
+use crate::message::TransferMessage;
+use crate::security::{self, OutflowWindow, RateLimit};
+use crate::state::{
+    BridgeConfig, ForeignEmitter, SupportedToken, TransferDirection, TransferRecord,
+    TransferStatus, WormholeEmitter, ETHEREUM_CHAIN_ID, SOLANA_CHAIN_ID,
+};
+
+/// Permissionless: sends a refundable inbound transfer back to its sender
+/// as a new transfer to the source chain. Nothing leaves the vault here, but
+/// the value is paid out on the source chain, so the refund counts against
+/// the same limits and breaker as the withdrawal it replaces.
+#[derive(Accounts)]
+pub struct RefundTransfer<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(mut, seeds = [BridgeConfig::SEED], bump = config.bump)]
+    pub config: Box<Account<'info, BridgeConfig>>,
+
+    #[account(
+        mut,
+        seeds = [TransferRecord::SEED, &transfer_record.transfer_id],
+        bump = transfer_record.bump,
+        constraint = transfer_record.direction == TransferDirection::Inbound
+            @ BridgeError::InvalidTransferTransition
+    )]
+    pub transfer_record: Box<Account<'info, TransferRecord>>,
+
+    #[account(
+        seeds = [SupportedToken::SEED, transfer_record.mint.as_ref()],
+        bump = supported_token.bump
+    )]
+    pub supported_token: Box<Account<'info, SupportedToken>>,
+
+    /// Registry entry of the chain the refund goes to.
+    #[account(
+        seeds = [ForeignEmitter::SEED, &transfer_record.source_chain.to_be_bytes()],
+        bump = foreign_emitter.bump
+    )]
+    pub foreign_emitter: Box<Account<'info, ForeignEmitter>>,
+
+    #[account(
+        mut,
+        seeds = [OutflowWindow::SEED, transfer_record.mint.as_ref()],
+        bump = outflow_window.bump
+    )]
+    pub outflow_window: Box<Account<'info, OutflowWindow>>,
+
+    /// Inbound limit of the source chain and mint that `withdraw` skipped.
+    #[account(
+        mut,
+        seeds = [
+            RateLimit::SEED,
+            &transfer_record.source_chain.to_be_bytes(),
+            transfer_record.mint.as_ref(),
+            &[TransferDirection::Inbound as u8],
+        ],
+        bump = rate_limit.bump
+    )]
+    pub rate_limit: Box<Account<'info, RateLimit>>,
+
+    #[account(mut, seeds = [WormholeEmitter::SEED], bump = wormhole_emitter.bump)]
+    pub wormhole_emitter: Box<Account<'info, WormholeEmitter>>,
+
+    /// CHECK: Core bridge config, validated by the Wormhole program.
+    #[account(mut, seeds = [BRIDGE_SEED], bump, seeds::program = wormhole_program.key())]
+    pub wormhole_bridge: UncheckedAccount<'info>,
+
+    /// CHECK: Core bridge fee collector, validated by the Wormhole program.
+    #[account(
+        mut,
+        seeds = [FEE_COLLECTOR_SEED],
+        bump,
+        seeds::program = wormhole_program.key()
+    )]
+    pub wormhole_fee_collector: UncheckedAccount<'info>,
+
+    /// CHECK: Sequence tracker of our emitter, owned by the Wormhole program.
+    #[account(
+        mut,
+        seeds = [SEQUENCE_SEED, wormhole_emitter.key().as_ref()],
+        bump,
+        seeds::program = wormhole_program.key()
+    )]
+    pub wormhole_sequence: UncheckedAccount<'info>,
+
+    /// CHECK: Created by the Wormhole program to hold the posted message.
+    #[account(
+        mut,
+        seeds = [
+            WormholeEmitter::MESSAGE_SEED,
+            &wormhole_emitter.sequence.to_be_bytes(),
+        ],
+        bump
+    )]
+    pub wormhole_message: UncheckedAccount<'info>,
+
+    /// CHECK: Must be the Wormhole core bridge named in the config.
+    #[account(executable, address = config.wormhole_program)]
+    pub wormhole_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<RefundTransfer>) -> Result<()> {
+    ctx.accounts.config.circuit_breaker.check_closed()?;
+    security::check_not_paused(
+        TransferDirection::Outbound,
+        &ctx.accounts.config.paused,
+        &ctx.accounts.supported_token.paused,
+        &ctx.accounts.foreign_emitter.paused,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let record = &mut ctx.accounts.transfer_record;
+    record.transition(TransferStatus::Refunded, now)?;
+    ctx.accounts.rate_limit.bucket.consume(record.amount_usd, now)?;
+    ctx.accounts.config.daily_volume.consume(record.amount_usd, now)?;
+    ctx.accounts.config.circuit_breaker.record_outflow(
+        &mut ctx.accounts.outflow_window,
+        record.amount_usd,
+        now,
+    )?;
+
+    // Ethereum addresses travel as their bare 20 bytes.
+    let sender = if record.source_chain == ETHEREUM_CHAIN_ID {
+        record.counterparty[12..].to_vec()
+    } else {
+        record.counterparty.to_vec()
+    };
+    let config = &mut ctx.accounts.config;
+    let nonce = config.transfer_nonce;
+    config.transfer_nonce = nonce.checked_add(1).ok_or(BridgeError::MathOverflow)?;
+
+    let refund_id = TransferRecord::refund_id(&record.transfer_id);
+    let payload = TransferMessage {
+        version: TransferMessage::VERSION,
+        transfer_id: refund_id,
+        sender: record.local_account.to_bytes().to_vec(),
+        recipient: sender,
+        // Paid out in the token the transfer was deposited in.
+        destination_token: [0; 32],
+        amount_usd: record.amount_usd,
+        // A refund must always settle.
+        min_destination_amount: 0,
+        nonce,
+        source_chain: SOLANA_CHAIN_ID,
+        timestamp: now,
+    }
+    .encode()?;
+
+    let emitter = &mut ctx.accounts.wormhole_emitter;
+    let sequence = emitter.sequence;
+    let sequence_bytes = sequence.to_be_bytes();
+    let emitter_seeds: &[&[u8]] = &[WormholeEmitter::SEED, &[emitter.bump]];
+    let message_seeds: &[&[u8]] = &[
+        WormholeEmitter::MESSAGE_SEED,
+        &sequence_bytes,
+        &[ctx.bumps.wormhole_message],
+    ];
+    wormhole::post_message(
+        PostMessage {
+            wormhole_program: &ctx.accounts.wormhole_program.to_account_info(),
+            bridge: &ctx.accounts.wormhole_bridge.to_account_info(),
+            message: &ctx.accounts.wormhole_message.to_account_info(),
+            emitter: &emitter.to_account_info(),
+            sequence: &ctx.accounts.wormhole_sequence.to_account_info(),
+            payer: &ctx.accounts.payer.to_account_info(),
+            fee_collector: &ctx.accounts.wormhole_fee_collector.to_account_info(),
+            clock: &ctx.accounts.clock.to_account_info(),
+            rent: &ctx.accounts.rent.to_account_info(),
+            system_program: &ctx.accounts.system_program.to_account_info(),
+        },
+        0,
+        payload,
+        Finality::Finalized,
+        &[emitter_seeds, message_seeds],
+    )?;
+    emitter.sequence = sequence.checked_add(1).ok_or(BridgeError::MathOverflow)?;
+    record.sequence = sequence;
+
+    emit!(TransferRefunded {
+        transfer_id: record.transfer_id,
+        refund_id,
+        sequence,
+    });
+    msg!(
+        "Refunded {} to chain {}, sequence {}",
+        record.amount_usd,
+        record.source_chain,
+        sequence
+    );
+    Ok(())
+}
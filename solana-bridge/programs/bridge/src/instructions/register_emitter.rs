@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::security::{has_role, PauseFlags, Role, RoleMember, TimelockAction, TimelockOperation};
+use crate::state::{BridgeConfig, ForeignEmitter};
+
+#[derive(Accounts)]
+#[instruction(chain: u16, address: [u8; 32])]
+pub struct RegisterEmitter<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = config.bump,
+        constraint = has_role(
+            &config,
+            &authority.key(),
+            role_member.as_deref(),
+            Role::EmitterManager
+        ) @ BridgeError::Unauthorized
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [RoleMember::SEED, &[role_member.role as u8], authority.key().as_ref()],
+        bump = role_member.bump
+    )]
+    pub role_member: Option<Account<'info, RoleMember>>,
+
+    /// Matured timelock operation scheduling exactly this change.
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            TimelockOperation::SEED,
+            &TimelockAction::RegisterEmitter { chain, address }.id(),
+        ],
+        bump = operation.bump
+    )]
+    pub operation: Account<'info, TimelockOperation>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ForeignEmitter::INIT_SPACE,
+        seeds = [ForeignEmitter::SEED, &chain.to_be_bytes()],
+        bump
+    )]
+    pub foreign_emitter: Account<'info, ForeignEmitter>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RegisterEmitter>, chain: u16, address: [u8; 32]) -> Result<()> {
+    ForeignEmitter::check_registration(chain, &address)?;
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts
+        .operation
+        .execute(ctx.accounts.authority.key(), now)?;
+
+    let emitter = &mut ctx.accounts.foreign_emitter;
+    emitter.chain = chain;
+    emitter.address = address;
+    emitter.paused = PauseFlags::default();
+    emitter.bump = ctx.bumps.foreign_emitter;
+
+    msg!("Emitter registered for chain {}", chain);
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::events::RoleRevoked;
+use crate::security::RoleMember;
+
+#[derive(Accounts)]
+pub struct RenounceRole<'info> {
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    #[account(
+        mut,
+        close = member,
+        seeds = [RoleMember::SEED, &[grant.role as u8], member.key().as_ref()],
+        bump = grant.bump
+    )]
+    pub grant: Account<'info, RoleMember>,
+}
+
+pub fn handler(ctx: Context<RenounceRole>) -> Result<()> {
+    let grant = &ctx.accounts.grant;
+    emit!(RoleRevoked {
+        role: grant.role,
+        member: grant.member,
+        revoked_by: grant.member,
+    });
+    msg!("{} renounced {:?}", grant.member, grant.role);
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::CircuitBreakerReset;
+use crate::security::{has_role, OutflowWindow, Role, RoleMember};
+use crate::state::BridgeConfig;
+
+#[derive(Accounts)]
+pub struct ResetCircuitBreaker<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BridgeConfig::SEED],
+        bump = config.bump,
+        constraint = has_role(&config, &authority.key(), role_member.as_deref(), Role::Admin)
+            @ BridgeError::Unauthorized,
+        constraint = config.circuit_breaker.is_tripped() @ BridgeError::CircuitBreakerNotTripped
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [RoleMember::SEED, &[role_member.role as u8], authority.key().as_ref()],
+        bump = role_member.bump
+    )]
+    pub role_member: Option<Account<'info, RoleMember>>,
+
+    /// Window of the mint that tripped the breaker. It is cleared so the
+    /// outflow that caused the trip does not immediately trip it again.
+    #[account(
+        mut,
+        seeds = [OutflowWindow::SEED, config.circuit_breaker.tripped_mint.as_ref()],
+        bump = outflow_window.bump
+    )]
+    pub outflow_window: Account<'info, OutflowWindow>,
+}
+
+pub fn handler(ctx: Context<ResetCircuitBreaker>) -> Result<()> {
+    let breaker = &mut ctx.accounts.config.circuit_breaker;
+    let reason = breaker
+        .trip_reason
+        .ok_or(BridgeError::CircuitBreakerNotTripped)?;
+    let mint = breaker.tripped_mint;
+    breaker.reset();
+    ctx.accounts.outflow_window.clear();
+
+    emit!(CircuitBreakerReset {
+        admin: ctx.accounts.authority.key(),
+        reason,
+        mint,
+        reset_at: Clock::get()?.unix_timestamp,
+    });
+    msg!("Circuit breaker reset by {}", ctx.accounts.authority.key());
+    Ok(())
+}
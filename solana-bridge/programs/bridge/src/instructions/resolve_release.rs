@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::ReleaseResolved;
+use crate::security::{has_role, Role, RoleMember};
+use crate::state::{BridgeConfig, PendingRelease, TokenLedger, TransferRecord, TransferStatus};
+
+#[derive(Accounts)]
+pub struct ResolveRelease<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = config.bump,
+        constraint = has_role(&config, &authority.key(), role_member.as_deref(), Role::Admin)
+            @ BridgeError::Unauthorized
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [RoleMember::SEED, &[role_member.role as u8], authority.key().as_ref()],
+        bump = role_member.bump
+    )]
+    pub role_member: Option<Account<'info, RoleMember>>,
+
+    #[account(
+        mut,
+        seeds = [PendingRelease::SEED, &pending_release.transfer_id],
+        bump = pending_release.bump,
+        constraint = pending_release.frozen @ BridgeError::ReleaseNotFrozen
+    )]
+    pub pending_release: Account<'info, PendingRelease>,
+
+    #[account(
+        mut,
+        seeds = [TransferRecord::SEED, &pending_release.transfer_id],
+        bump = transfer_record.bump
+    )]
+    pub transfer_record: Account<'info, TransferRecord>,
+
+    #[account(
+        mut,
+        seeds = [TokenLedger::SEED, pending_release.mint.as_ref()],
+        bump = ledger.bump
+    )]
+    pub ledger: Account<'info, TokenLedger>,
+
+    /// CHECK: Receives the pending release's rent back if it is rejected.
+    #[account(mut, address = pending_release.payer)]
+    pub payer: UncheckedAccount<'info>,
+}
+
+/// Settles a frozen release. Approving lifts the freeze and the release can
+/// be finalized once unlocked; rejecting fails the transfer and keeps the
+/// funds in the vault.
+pub fn handler(ctx: Context<ResolveRelease>, approve: bool) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let transfer_id = ctx.accounts.pending_release.transfer_id;
+
+    if approve {
+        ctx.accounts.pending_release.unfreeze()?;
+    } else {
+        ctx.accounts
+            .transfer_record
+            .transition(TransferStatus::Failed, now)?;
+        ctx.accounts
+            .ledger
+            .drop_held(ctx.accounts.pending_release.amount)?;
+        ctx.accounts
+            .pending_release
+            .close(ctx.accounts.payer.to_account_info())?;
+    }
+
+    emit!(ReleaseResolved {
+        transfer_id,
+        approved: approve,
+        authority: ctx.accounts.authority.key(),
+    });
+    msg!(
+        "Frozen release {} by {}",
+        if approve { "approved" } else { "rejected" },
+        ctx.accounts.authority.key()
+    );
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::RoleRevoked;
+use crate::security::{can_manage_role, RoleMember};
+use crate::state::BridgeConfig;
+
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = config.bump,
+        constraint = can_manage_role(&config, &authority.key(), role_member.as_deref(), grant.role)
+            @ BridgeError::Unauthorized
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [RoleMember::SEED, &[role_member.role as u8], authority.key().as_ref()],
+        bump = role_member.bump
+    )]
+    pub role_member: Option<Account<'info, RoleMember>>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [RoleMember::SEED, &[grant.role as u8], grant.member.as_ref()],
+        bump = grant.bump
+    )]
+    pub grant: Account<'info, RoleMember>,
+}
+
+pub fn handler(ctx: Context<RevokeRole>) -> Result<()> {
+    let grant = &ctx.accounts.grant;
+    emit!(RoleRevoked {
+        role: grant.role,
+        member: grant.member,
+        revoked_by: ctx.accounts.authority.key(),
+    });
+    msg!("Revoked {:?} from {}", grant.role, grant.member);
+    Ok(())
+}
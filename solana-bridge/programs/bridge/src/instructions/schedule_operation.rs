@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::OperationScheduled;
+use crate::security::{has_role, RoleMember, TimelockAction, TimelockOperation};
+use crate::state::BridgeConfig;
+
+#[derive(Accounts)]
+#[instruction(action: TimelockAction)]
+pub struct ScheduleOperation<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = config.bump,
+        constraint = has_role(&config, &authority.key(), role_member.as_deref(), action.role())
+            @ BridgeError::Unauthorized
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [RoleMember::SEED, &[role_member.role as u8], authority.key().as_ref()],
+        bump = role_member.bump
+    )]
+    pub role_member: Option<Account<'info, RoleMember>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + TimelockOperation::INIT_SPACE,
+        seeds = [TimelockOperation::SEED, &action.id()],
+        bump
+    )]
+    pub operation: Account<'info, TimelockOperation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ScheduleOperation>, action: TimelockAction) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let eta = now
+        .checked_add(ctx.accounts.config.timelock_delay)
+        .ok_or(BridgeError::MathOverflow)?;
+
+    let operation = &mut ctx.accounts.operation;
+    operation.id = action.id();
+    operation.action = action;
+    operation.proposer = ctx.accounts.authority.key();
+    operation.scheduled_at = now;
+    operation.eta = eta;
+    operation.bump = ctx.bumps.operation;
+
+    emit!(OperationScheduled {
+        id: operation.id,
+        action: operation.action.clone(),
+        proposer: operation.proposer,
+        eta,
+    });
+    msg!("Scheduled {:?}, ready at {}", operation.action, eta);
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::PauseUpdated;
+use crate::security::{self, has_role, has_role_or_admin, PauseFlags, PauseScope, Role, RoleMember};
+use crate::state::BridgeConfig;
+
+#[derive(Accounts)]
+pub struct SetBridgePause<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BridgeConfig::SEED],
+        bump = config.bump,
+        constraint = has_role_or_admin(
+            &config,
+            &authority.key(),
+            role_member.as_deref(),
+            Role::Pauser
+        ) @ BridgeError::Unauthorized
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [RoleMember::SEED, &[role_member.role as u8], authority.key().as_ref()],
+        bump = role_member.bump
+    )]
+    pub role_member: Option<Account<'info, RoleMember>>,
+}
+
+pub fn handler(ctx: Context<SetBridgePause>, paused: PauseFlags) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    let can_unpause = has_role(
+        &ctx.accounts.config,
+        &authority,
+        ctx.accounts.role_member.as_deref(),
+        Role::Admin,
+    );
+    let config = &mut ctx.accounts.config;
+    let previous = config.paused;
+    security::check_pause_change(can_unpause, &previous, &paused)?;
+    config.paused = paused;
+
+    emit!(PauseUpdated {
+        scope: PauseScope::Bridge,
+        previous,
+        paused,
+        authority,
+    });
+    msg!("Bridge pause set to {:?}", paused);
+    Ok(())
+}
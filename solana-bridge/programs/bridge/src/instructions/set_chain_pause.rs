@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::PauseUpdated;
+use crate::security::{self, has_role, has_role_or_admin, PauseFlags, PauseScope, Role, RoleMember};
+use crate::state::{BridgeConfig, ForeignEmitter};
+
+#[derive(Accounts)]
+pub struct SetChainPause<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = config.bump,
+        constraint = has_role_or_admin(
+            &config,
+            &authority.key(),
+            role_member.as_deref(),
+            Role::Pauser
+        ) @ BridgeError::Unauthorized
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [RoleMember::SEED, &[role_member.role as u8], authority.key().as_ref()],
+        bump = role_member.bump
+    )]
+    pub role_member: Option<Account<'info, RoleMember>>,
+
+    #[account(
+        mut,
+        seeds = [ForeignEmitter::SEED, &foreign_emitter.chain.to_be_bytes()],
+        bump = foreign_emitter.bump
+    )]
+    pub foreign_emitter: Account<'info, ForeignEmitter>,
+}
+
+pub fn handler(ctx: Context<SetChainPause>, paused: PauseFlags) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    let can_unpause = has_role(
+        &ctx.accounts.config,
+        &authority,
+        ctx.accounts.role_member.as_deref(),
+        Role::Admin,
+    );
+    let emitter = &mut ctx.accounts.foreign_emitter;
+    let previous = emitter.paused;
+    security::check_pause_change(can_unpause, &previous, &paused)?;
+    emitter.paused = paused;
+
+    emit!(PauseUpdated {
+        scope: PauseScope::Chain {
+            chain: emitter.chain
+        },
+        previous,
+        paused,
+        authority,
+    });
+    msg!("Chain {} pause set to {:?}", emitter.chain, paused);
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::CouncilUpdated;
+use crate::state::Council;
+
+/// Only reachable through an executed proposal: the council authority PDA
+/// can sign nothing else.
+#[derive(Accounts)]
+pub struct SetCouncil<'info> {
+    #[account(seeds = [Council::AUTHORITY_SEED], bump = council.authority_bump)]
+    pub council_authority: Signer<'info>,
+
+    #[account(mut, seeds = [Council::SEED], bump = council.bump)]
+    pub council: Account<'info, Council>,
+}
+
+pub fn handler(ctx: Context<SetCouncil>, members: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    Council::check_membership(&members, threshold)?;
+
+    let council = &mut ctx.accounts.council;
+    council.members = members;
+    council.threshold = threshold;
+    council.epoch = council
+        .epoch
+        .checked_add(1)
+        .ok_or(BridgeError::MathOverflow)?;
+
+    emit!(CouncilUpdated {
+        members: council.members.clone(),
+        threshold,
+        epoch: council.epoch,
+    });
+    msg!(
+        "Council set to {} of {}, epoch {}",
+        threshold,
+        council.members.len(),
+        council.epoch
+    );
+    Ok(())
+}
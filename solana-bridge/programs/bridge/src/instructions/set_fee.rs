@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::FeeUpdated;
+use crate::security::{has_role, Role, RoleMember, TimelockAction, TimelockOperation};
+use crate::state::{BridgeConfig, MAX_FEE_BPS};
+
+#[derive(Accounts)]
+#[instruction(fee_bps: u16)]
+pub struct SetFee<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BridgeConfig::SEED],
+        bump = config.bump,
+        constraint = has_role(&config, &authority.key(), role_member.as_deref(), Role::FeeManager)
+            @ BridgeError::Unauthorized
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [RoleMember::SEED, &[role_member.role as u8], authority.key().as_ref()],
+        bump = role_member.bump
+    )]
+    pub role_member: Option<Account<'info, RoleMember>>,
+
+    /// Matured timelock operation scheduling exactly this change.
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            TimelockOperation::SEED,
+            &TimelockAction::SetFee { fee_bps }.id(),
+        ],
+        bump = operation.bump
+    )]
+    pub operation: Account<'info, TimelockOperation>,
+}
+
+pub fn handler(ctx: Context<SetFee>, fee_bps: u16) -> Result<()> {
+    require!(fee_bps <= MAX_FEE_BPS, BridgeError::InvalidFee);
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts
+        .operation
+        .execute(ctx.accounts.authority.key(), now)?;
+
+    let config = &mut ctx.accounts.config;
+    let previous_bps = config.fee_bps;
+    config.fee_bps = fee_bps;
+
+    emit!(FeeUpdated {
+        previous_bps,
+        fee_bps,
+        authority: ctx.accounts.authority.key(),
+    });
+    msg!("Bridge fee set to {} bps", fee_bps);
+    Ok(())
+}
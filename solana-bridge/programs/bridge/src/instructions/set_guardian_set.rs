@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::security::{has_role, Role, RoleMember, TimelockAction, TimelockOperation};
+use crate::state::{BridgeConfig, GuardianSet, GUARDIAN_SET_EXPIRATION};
+
+#[derive(Accounts)]
+#[instruction(index: u32, keys: Vec<[u8; 20]>)]
+pub struct SetGuardianSet<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BridgeConfig::SEED],
+        bump = config.bump,
+        constraint = has_role(&config, &authority.key(), role_member.as_deref(), Role::Admin)
+            @ BridgeError::Unauthorized
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [RoleMember::SEED, &[role_member.role as u8], authority.key().as_ref()],
+        bump = role_member.bump
+    )]
+    pub role_member: Option<Account<'info, RoleMember>>,
+
+    /// Matured timelock operation scheduling exactly this change.
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            TimelockOperation::SEED,
+            &TimelockAction::SetGuardianSet { index, keys: keys.clone() }.id(),
+        ],
+        bump = operation.bump
+    )]
+    pub operation: Account<'info, TimelockOperation>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + GuardianSet::INIT_SPACE,
+        seeds = [GuardianSet::SEED, &index.to_be_bytes()],
+        bump
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+
+    /// Set being replaced, required for every set after the first. It keeps
+    /// verifying for `GUARDIAN_SET_EXPIRATION` and then expires.
+    #[account(
+        mut,
+        seeds = [GuardianSet::SEED, &config.guardian_set_index.to_be_bytes()],
+        bump = previous_guardian_set.bump
+    )]
+    pub previous_guardian_set: Option<Account<'info, GuardianSet>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetGuardianSet>, index: u32, keys: Vec<[u8; 20]>) -> Result<()> {
+    GuardianSet::check_keys(&keys)?;
+    let config = &mut ctx.accounts.config;
+    GuardianSet::check_next_index(
+        config.guardian_set_index,
+        index,
+        ctx.accounts.previous_guardian_set.is_some(),
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts
+        .operation
+        .execute(ctx.accounts.authority.key(), now)?;
+    if let Some(previous) = ctx.accounts.previous_guardian_set.as_mut() {
+        previous.expiration_time = now
+            .checked_add(GUARDIAN_SET_EXPIRATION)
+            .ok_or(BridgeError::MathOverflow)?;
+        msg!(
+            "Guardian set {} expires at {}",
+            previous.index,
+            previous.expiration_time
+        );
+    }
+
+    let guardian_set = &mut ctx.accounts.guardian_set;
+    guardian_set.index = index;
+    guardian_set.keys = keys;
+    guardian_set.created_at = now;
+    guardian_set.expiration_time = 0;
+    guardian_set.bump = ctx.bumps.guardian_set;
+    config.guardian_set_index = index;
+
+    msg!(
+        "Guardian set {} registered with {} guardians",
+        index,
+        guardian_set.keys.len()
+    );
+    Ok(())
+}
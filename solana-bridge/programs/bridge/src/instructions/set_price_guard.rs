@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::integrations::pyth::PriceFeed;
+use crate::security::{has_role, PriceGuard, Role, RoleMember};
+use crate::state::{BridgeConfig, SupportedToken};
+
+#[derive(Accounts)]
+#[instruction(guard: PriceGuard)]
+pub struct SetPriceGuard<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = config.bump,
+        constraint = has_role(&config, &authority.key(), role_member.as_deref(), Role::Operator)
+            @ BridgeError::Unauthorized
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [RoleMember::SEED, &[role_member.role as u8], authority.key().as_ref()],
+        bump = role_member.bump
+    )]
+    pub role_member: Option<Account<'info, RoleMember>>,
+
+    #[account(
+        mut,
+        seeds = [SupportedToken::SEED, supported_token.mint.as_ref()],
+        bump = supported_token.bump
+    )]
+    pub supported_token: Account<'info, SupportedToken>,
+
+    /// CHECK: Parsed below; required unless the guard is being disabled.
+    #[account(address = guard.feed)]
+    pub price_feed: Option<UncheckedAccount<'info>>,
+}
+
+pub fn handler(ctx: Context<SetPriceGuard>, guard: PriceGuard) -> Result<()> {
+    guard.validate()?;
+    if guard.is_enabled() {
+        // Refuse a feed that is not a price account at all.
+        let feed = ctx
+            .accounts
+            .price_feed
+            .as_ref()
+            .ok_or(BridgeError::InvalidPriceFeed)?;
+        PriceFeed::load(feed)?;
+    }
+
+    let token = &mut ctx.accounts.supported_token;
+    token.price_guard = guard;
+
+    msg!(
+        "Price guard of {} set to {} ({} to {})",
+        token.mint,
+        guard.feed,
+        guard.min_price_usd,
+        guard.max_price_usd
+    );
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::errors::BridgeError;
+use crate::security::{has_role, RateLimit, Role, RoleMember, TokenBucket};
+use crate::state::{BridgeConfig, TransferDirection, SOLANA_CHAIN_ID};
+
+#[derive(Accounts)]
+#[instruction(chain: u16, direction: TransferDirection)]
+pub struct SetRateLimit<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = config.bump,
+        constraint = has_role(&config, &authority.key(), role_member.as_deref(), Role::Operator)
+            @ BridgeError::Unauthorized
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [RoleMember::SEED, &[role_member.role as u8], authority.key().as_ref()],
+        bump = role_member.bump
+    )]
+    pub role_member: Option<Account<'info, RoleMember>>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + RateLimit::INIT_SPACE,
+        seeds = [
+            RateLimit::SEED,
+            &chain.to_be_bytes(),
+            mint.key().as_ref(),
+            &[direction as u8],
+        ],
+        bump
+    )]
+    pub rate_limit: Account<'info, RateLimit>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SetRateLimit>,
+    chain: u16,
+    direction: TransferDirection,
+    capacity: u64,
+    refill_per_second: u64,
+) -> Result<()> {
+    require!(
+        chain != 0 && chain != SOLANA_CHAIN_ID,
+        BridgeError::InvalidForeignEmitter
+    );
+    RateLimit::check_params(capacity, refill_per_second)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let limit = &mut ctx.accounts.rate_limit;
+    if limit.mint == Pubkey::default() {
+        limit.chain = chain;
+        limit.mint = ctx.accounts.mint.key();
+        limit.direction = direction;
+        limit.bucket = TokenBucket::new(capacity, refill_per_second, now);
+        limit.bump = ctx.bumps.rate_limit;
+    } else {
+        limit.bucket.reconfigure(capacity, refill_per_second, 1, now);
+    }
+
+    msg!(
+        "Rate limit for chain {} {:?}: capacity {}, refill {}/s",
+        chain,
+        direction,
+        capacity,
+        refill_per_second
+    );
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::security::{has_role, Role, RoleMember, TimelockAction, TimelockOperation};
+use crate::state::BridgeConfig;
+
+#[derive(Accounts)]
+#[instruction(threshold: u64, delay: i64)]
+pub struct SetReleaseDelay<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BridgeConfig::SEED],
+        bump = config.bump,
+        constraint = has_role(&config, &authority.key(), role_member.as_deref(), Role::Admin)
+            @ BridgeError::Unauthorized
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [RoleMember::SEED, &[role_member.role as u8], authority.key().as_ref()],
+        bump = role_member.bump
+    )]
+    pub role_member: Option<Account<'info, RoleMember>>,
+
+    /// Matured timelock operation scheduling exactly this change.
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            TimelockOperation::SEED,
+            &TimelockAction::SetReleaseDelay { threshold, delay }.id(),
+        ],
+        bump = operation.bump
+    )]
+    pub operation: Account<'info, TimelockOperation>,
+}
+
+/// Releases already pending keep the unlock time they were queued with.
+pub fn handler(ctx: Context<SetReleaseDelay>, threshold: u64, delay: i64) -> Result<()> {
+    BridgeConfig::validate_release_delay(threshold, delay)?;
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts
+        .operation
+        .execute(ctx.accounts.authority.key(), now)?;
+
+    let config = &mut ctx.accounts.config;
+    config.release_delay_threshold = threshold;
+    config.release_delay = delay;
+
+    msg!(
+        "Withdrawals above {} now held for {} seconds",
+        threshold,
+        delay
+    );
+    Ok(())
+}
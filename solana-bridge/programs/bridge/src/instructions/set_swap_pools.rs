@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::integrations::orca::Whirlpool;
+use crate::integrations::stable_swap::StablePool;
+use crate::security::{has_role, Role, RoleMember};
+use crate::state::{BridgeConfig, SupportedToken, SwapPools};
+
+#[derive(Accounts)]
+#[instruction(pools: SwapPools)]
+pub struct SetSwapPools<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = config.bump,
+        constraint = has_role(&config, &authority.key(), role_member.as_deref(), Role::Operator)
+            @ BridgeError::Unauthorized
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [RoleMember::SEED, &[role_member.role as u8], authority.key().as_ref()],
+        bump = role_member.bump
+    )]
+    pub role_member: Option<Account<'info, RoleMember>>,
+
+    #[account(
+        mut,
+        seeds = [SupportedToken::SEED, supported_token.mint.as_ref()],
+        bump = supported_token.bump
+    )]
+    pub supported_token: Account<'info, SupportedToken>,
+
+    /// CHECK: Parsed below; required unless the whirlpool is being cleared.
+    #[account(address = pools.whirlpool)]
+    pub whirlpool: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Parsed below; required unless the stable pool is being cleared.
+    #[account(address = pools.stable_pool)]
+    pub stable_pool: Option<UncheckedAccount<'info>>,
+}
+
+pub fn handler(ctx: Context<SetSwapPools>, pools: SwapPools) -> Result<()> {
+    let mint = ctx.accounts.supported_token.mint;
+    if pools.whirlpool != Pubkey::default() {
+        // Refuse a pool that cannot pay out in this mint.
+        let whirlpool = ctx
+            .accounts
+            .whirlpool
+            .as_ref()
+            .ok_or(BridgeError::InvalidWhirlpool)?;
+        let pool = Whirlpool::load(whirlpool)?;
+        require!(
+            pool.token_mint_a == mint || pool.token_mint_b == mint,
+            BridgeError::InvalidWhirlpool
+        );
+    }
+    if pools.stable_pool != Pubkey::default() {
+        let stable_pool = ctx
+            .accounts
+            .stable_pool
+            .as_ref()
+            .ok_or(BridgeError::InvalidStablePool)?;
+        let pool = StablePool::load(stable_pool)?.pool;
+        require!(
+            pool.mint_a == mint || pool.mint_b == mint,
+            BridgeError::InvalidStablePool
+        );
+    }
+
+    ctx.accounts.supported_token.swap_pools = pools;
+
+    msg!("Swap pools of {} set to {:?}", mint, pools);
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::PauseUpdated;
+use crate::security::{self, has_role, has_role_or_admin, PauseFlags, PauseScope, Role, RoleMember};
+use crate::state::{BridgeConfig, SupportedToken};
+
+#[derive(Accounts)]
+pub struct SetTokenPause<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = config.bump,
+        constraint = has_role_or_admin(
+            &config,
+            &authority.key(),
+            role_member.as_deref(),
+            Role::Pauser
+        ) @ BridgeError::Unauthorized
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [RoleMember::SEED, &[role_member.role as u8], authority.key().as_ref()],
+        bump = role_member.bump
+    )]
+    pub role_member: Option<Account<'info, RoleMember>>,
+
+    #[account(
+        mut,
+        seeds = [SupportedToken::SEED, supported_token.mint.as_ref()],
+        bump = supported_token.bump
+    )]
+    pub supported_token: Account<'info, SupportedToken>,
+}
+
+pub fn handler(ctx: Context<SetTokenPause>, paused: PauseFlags) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    let can_unpause = has_role(
+        &ctx.accounts.config,
+        &authority,
+        ctx.accounts.role_member.as_deref(),
+        Role::Admin,
+    );
+    let token = &mut ctx.accounts.supported_token;
+    let previous = token.paused;
+    security::check_pause_change(can_unpause, &previous, &paused)?;
+    token.paused = paused;
+
+    emit!(PauseUpdated {
+        scope: PauseScope::Token { mint: token.mint },
+        previous,
+        paused,
+        authority,
+    });
+    msg!("Token {} pause set to {:?}", token.mint, paused);
+    Ok(())
+}
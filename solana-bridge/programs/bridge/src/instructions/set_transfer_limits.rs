@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::security::{
+    has_role, Role, RoleMember, TimelockAction, TimelockOperation, SECONDS_PER_DAY,
+};
+use crate::state::BridgeConfig;
+
+#[derive(Accounts)]
+#[instruction(min_transfer: u64, max_transfer: u64, daily_volume_cap: u64)]
+pub struct SetTransferLimits<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BridgeConfig::SEED],
+        bump = config.bump,
+        constraint = has_role(&config, &authority.key(), role_member.as_deref(), Role::Admin)
+            @ BridgeError::Unauthorized
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [RoleMember::SEED, &[role_member.role as u8], authority.key().as_ref()],
+        bump = role_member.bump
+    )]
+    pub role_member: Option<Account<'info, RoleMember>>,
+
+    /// Matured timelock operation scheduling exactly this change.
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            TimelockOperation::SEED,
+            &TimelockAction::SetTransferLimits {
+                min_transfer,
+                max_transfer,
+                daily_volume_cap,
+            }
+            .id(),
+        ],
+        bump = operation.bump
+    )]
+    pub operation: Account<'info, TimelockOperation>,
+}
+
+pub fn handler(
+    ctx: Context<SetTransferLimits>,
+    min_transfer: u64,
+    max_transfer: u64,
+    daily_volume_cap: u64,
+) -> Result<()> {
+    BridgeConfig::validate_transfer_limits(min_transfer, max_transfer, daily_volume_cap)?;
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts
+        .operation
+        .execute(ctx.accounts.authority.key(), now)?;
+
+    let config = &mut ctx.accounts.config;
+    config.min_transfer = min_transfer;
+    config.max_transfer = max_transfer;
+    config.daily_volume_cap = daily_volume_cap;
+    config
+        .daily_volume
+        .reconfigure(daily_volume_cap, daily_volume_cap, SECONDS_PER_DAY, now);
+
+    msg!(
+        "Transfer limits set: {} to {}, {} per day",
+        min_transfer,
+        max_transfer,
+        daily_volume_cap
+    );
+    Ok(())
+}
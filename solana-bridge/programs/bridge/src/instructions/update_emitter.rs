@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::security::{has_role, Role, RoleMember, TimelockAction, TimelockOperation};
+use crate::state::{BridgeConfig, ForeignEmitter};
+
+#[derive(Accounts)]
+#[instruction(address: [u8; 32])]
+pub struct UpdateEmitter<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = config.bump,
+        constraint = has_role(
+            &config,
+            &authority.key(),
+            role_member.as_deref(),
+            Role::EmitterManager
+        ) @ BridgeError::Unauthorized
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [RoleMember::SEED, &[role_member.role as u8], authority.key().as_ref()],
+        bump = role_member.bump
+    )]
+    pub role_member: Option<Account<'info, RoleMember>>,
+
+    #[account(
+        mut,
+        seeds = [ForeignEmitter::SEED, &foreign_emitter.chain.to_be_bytes()],
+        bump = foreign_emitter.bump
+    )]
+    pub foreign_emitter: Account<'info, ForeignEmitter>,
+
+    /// Matured timelock operation scheduling exactly this change.
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            TimelockOperation::SEED,
+            &TimelockAction::UpdateEmitter { chain: foreign_emitter.chain, address }.id(),
+        ],
+        bump = operation.bump
+    )]
+    pub operation: Account<'info, TimelockOperation>,
+}
+
+pub fn handler(ctx: Context<UpdateEmitter>, address: [u8; 32]) -> Result<()> {
+    let emitter = &mut ctx.accounts.foreign_emitter;
+    ForeignEmitter::check_registration(emitter.chain, &address)?;
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts
+        .operation
+        .execute(ctx.accounts.authority.key(), now)?;
+    emitter.address = address;
+
+    msg!("Emitter updated for chain {}", emitter.chain);
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::instructions::TokenParams;
+use crate::security::{has_role, Role, RoleMember};
+use crate::state::{BridgeConfig, SupportedToken};
+
+#[derive(Accounts)]
+pub struct UpdateToken<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = config.bump,
+        constraint = has_role(&config, &authority.key(), role_member.as_deref(), Role::Operator)
+            @ BridgeError::Unauthorized
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [RoleMember::SEED, &[role_member.role as u8], authority.key().as_ref()],
+        bump = role_member.bump
+    )]
+    pub role_member: Option<Account<'info, RoleMember>>,
+
+    #[account(
+        mut,
+        seeds = [SupportedToken::SEED, supported_token.mint.as_ref()],
+        bump = supported_token.bump
+    )]
+    pub supported_token: Account<'info, SupportedToken>,
+}
+
+pub fn handler(ctx: Context<UpdateToken>, params: TokenParams, enabled: bool) -> Result<()> {
+    params.validate()?;
+
+    let token = &mut ctx.accounts.supported_token;
+    token.min_amount_usd = params.min_amount_usd;
+    token.max_amount_usd = params.max_amount_usd;
+    token.enabled = enabled;
+
+    msg!("Token {} updated, enabled: {}", token.mint, enabled);
+    Ok(())
+}
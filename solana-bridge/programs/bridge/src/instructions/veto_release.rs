@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::ReleaseVetoed;
+use crate::security::{has_role_or_admin, Role, RoleMember};
+use crate::state::{BridgeConfig, PendingRelease};
+
+#[derive(Accounts)]
+pub struct VetoRelease<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = config.bump,
+        constraint = has_role_or_admin(
+            &config,
+            &authority.key(),
+            role_member.as_deref(),
+            Role::Watcher
+        ) @ BridgeError::Unauthorized
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [RoleMember::SEED, &[role_member.role as u8], authority.key().as_ref()],
+        bump = role_member.bump
+    )]
+    pub role_member: Option<Account<'info, RoleMember>>,
+
+    #[account(
+        mut,
+        seeds = [PendingRelease::SEED, &pending_release.transfer_id],
+        bump = pending_release.bump
+    )]
+    pub pending_release: Account<'info, PendingRelease>,
+}
+
+/// Freezes a pending release. Watchers can act after the window too, as
+/// long as nobody has finalized the release yet.
+pub fn handler(ctx: Context<VetoRelease>) -> Result<()> {
+    let watcher = ctx.accounts.authority.key();
+    let pending = &mut ctx.accounts.pending_release;
+    pending.freeze(watcher)?;
+
+    emit!(ReleaseVetoed {
+        transfer_id: pending.transfer_id,
+        watcher,
+    });
+    msg!("Release of {} frozen by {}", pending.amount, watcher);
+    Ok(())
+}
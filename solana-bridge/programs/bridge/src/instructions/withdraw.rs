@@ -0,0 +1,613 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::errors::BridgeError;
+use crate::events::{ReleaseQueued, TransferRefundable};
+use crate::integrations::orca::{self, WhirlpoolSwap, WHIRLPOOL_PROGRAM_ID};
+use crate::integrations::stable_swap::{self, StableSwap};
+use crate::message::TransferMessage;
+use crate::security::{self, OutflowWindow, RateLimit};
+use crate::state::{
+    to_universal_address, BridgeConfig, Claim, ForeignEmitter, GuardianSet, PendingRelease,
+    SupportedToken, TokenLedger, TransferDirection, TransferRecord, TransferStatus, MAX_BPS,
+    SOLANA_CHAIN_ID,
+};
+use crate::verification::ParsedVaa;
+
+#[derive(Accounts)]
+#[instruction(vaa: Vec<u8>)]
+pub struct Withdraw<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(mut, seeds = [BridgeConfig::SEED], bump = config.bump)]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [
+            GuardianSet::SEED,
+            &ParsedVaa::peek_guardian_set_index(&vaa).to_be_bytes(),
+        ],
+        bump = guardian_set.bump
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+
+    #[account(
+        seeds = [
+            ForeignEmitter::SEED,
+            &ParsedVaa::peek_emitter_chain(&vaa).to_be_bytes(),
+        ],
+        bump = foreign_emitter.bump
+    )]
+    pub foreign_emitter: Account<'info, ForeignEmitter>,
+
+    // `init_if_needed` so a replay fails with `AlreadyProcessed` instead of
+    // a generic "account already in use".
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + Claim::INIT_SPACE,
+        seeds = [
+            Claim::SEED,
+            &ParsedVaa::peek_emitter_chain(&vaa).to_be_bytes(),
+            &ParsedVaa::peek_emitter_address(&vaa),
+            &ParsedVaa::peek_sequence(&vaa).to_be_bytes(),
+        ],
+        bump,
+        constraint = !claim.processed @ BridgeError::AlreadyProcessed
+    )]
+    pub claim: Account<'info, Claim>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + TransferRecord::INIT_SPACE,
+        seeds = [
+            TransferRecord::SEED,
+            &TransferMessage::peek_transfer_id(ParsedVaa::peek_payload(&vaa)),
+        ],
+        bump
+    )]
+    pub transfer_record: Account<'info, TransferRecord>,
+
+    /// Required when the amount is above `config.release_delay_threshold`,
+    /// and must be left out otherwise; the funds are then held until the
+    /// delay passes.
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + PendingRelease::INIT_SPACE,
+        seeds = [
+            PendingRelease::SEED,
+            &TransferMessage::peek_transfer_id(ParsedVaa::peek_payload(&vaa)),
+        ],
+        bump
+    )]
+    pub pending_release: Option<Account<'info, PendingRelease>>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        seeds = [SupportedToken::SEED, mint.key().as_ref()],
+        bump = supported_token.bump
+    )]
+    pub supported_token: Account<'info, SupportedToken>,
+
+    /// CHECK: Price account named by the token's price guard; parsed by the
+    /// guard. Only needed when the guard is enabled.
+    #[account(address = supported_token.price_guard.feed)]
+    pub price_feed: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [OutflowWindow::SEED, mint.key().as_ref()],
+        bump = outflow_window.bump
+    )]
+    pub outflow_window: Account<'info, OutflowWindow>,
+
+    #[account(
+        mut,
+        seeds = [
+            RateLimit::SEED,
+            &ParsedVaa::peek_emitter_chain(&vaa).to_be_bytes(),
+            mint.key().as_ref(),
+            &[TransferDirection::Inbound as u8],
+        ],
+        bump = rate_limit.bump
+    )]
+    pub rate_limit: Account<'info, RateLimit>,
+
+    #[account(
+        mut,
+        seeds = [TokenLedger::SEED, mint.key().as_ref()],
+        bump = ledger.bump
+    )]
+    pub ledger: Account<'info, TokenLedger>,
+
+    /// Recipient's account of the message's destination token. When that is
+    /// not `mint`, the vault's tokens are swapped into it.
+    #[account(mut)]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [BridgeConfig::VAULT_SEED, mint.key().as_ref()],
+        bump = supported_token.vault_bump,
+        token::mint = mint,
+        token::authority = vault_authority
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// CHECK: Data-less PDA that owns the vault token accounts.
+    #[account(
+        seeds = [BridgeConfig::VAULT_AUTHORITY_SEED],
+        bump = config.vault_authority_bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    // Swap accounts, passed only when the recipient's account is not of
+    // `mint`. The swap goes through the StableSwap pool when one is passed,
+    // and through the whirlpool otherwise. The pool must be the one pinned on
+    // `output_token`; its other accounts are checked by the
+    // `swap_exact_input` of each venue.
+    #[account(
+        seeds = [SupportedToken::SEED, recipient_token_account.mint.as_ref()],
+        bump = output_token.bump
+    )]
+    pub output_token: Option<Account<'info, SupportedToken>>,
+
+    /// CHECK: Price account named by the output token's price guard; checked
+    /// by the guard. Only needed when that guard is enabled.
+    pub output_price_feed: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Must be the Whirlpool program.
+    #[account(address = WHIRLPOOL_PROGRAM_ID)]
+    pub whirlpool_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Must be `output_token.swap_pools.whirlpool`.
+    #[account(mut)]
+    pub whirlpool: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Token A vault of the pool.
+    #[account(mut)]
+    pub whirlpool_vault_a: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Token B vault of the pool.
+    #[account(mut)]
+    pub whirlpool_vault_b: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Tick array holding the current tick.
+    #[account(mut)]
+    pub tick_array_0: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Next tick array in the swap direction.
+    #[account(mut)]
+    pub tick_array_1: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Tick array after `tick_array_1`.
+    #[account(mut)]
+    pub tick_array_2: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Oracle PDA of the pool.
+    #[account(mut)]
+    pub whirlpool_oracle: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Must be the amm program.
+    #[account(address = amm::ID)]
+    pub amm_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Must be `output_token.swap_pools.stable_pool`.
+    #[account(mut)]
+    pub stable_pool: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Token A vault of the amm pool.
+    #[account(mut)]
+    pub stable_pool_vault_a: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Token B vault of the amm pool.
+    #[account(mut)]
+    pub stable_pool_vault_b: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Withdraw<'info> {
+    /// Sells `amount` from the vault through the StableSwap pool or the
+    /// whirlpool into the recipient's account. Returns the amount the
+    /// recipient received.
+    fn swap_from_vault(
+        &self,
+        amount: u64,
+        amount_usd: u64,
+        min_destination_amount: u64,
+        now: i64,
+    ) -> Result<u64> {
+        let output_token = self
+            .output_token
+            .as_ref()
+            .ok_or(BridgeError::InvalidWhirlpool)?;
+        output_token.check_enabled()?;
+        // The recipient ends up holding the output token, so it must be on
+        // its peg as well.
+        output_token
+            .price_guard
+            .check(self.output_price_feed.as_deref(), now)?;
+
+        // The recipient gets at least their floor, and never loses more than
+        // the configured slippage on the transfer's value.
+        let max_slippage_bps = self.config.max_slippage_bps;
+        let slippage_floor =
+            amount_usd as u128 * (MAX_BPS - max_slippage_bps) as u128 / MAX_BPS as u128;
+        let min_out = output_token.from_usd(min_destination_amount.max(slippage_floor as u64))?;
+
+        let seeds = self.config.vault_authority_seeds();
+        if let Some(stable_pool) = self.stable_pool.as_deref() {
+            require_keys_eq!(
+                *stable_pool.key,
+                output_token.swap_pools.stable_pool,
+                BridgeError::InvalidStablePool
+            );
+            return stable_swap::stable_swap_exact_input(
+                StableSwap {
+                    amm_program: swap_account(&self.amm_program, BridgeError::InvalidStablePool)?,
+                    token_program: &self.token_program,
+                    token_authority: &self.vault_authority,
+                    pool: stable_pool,
+                    source: self.vault.as_ref(),
+                    destination: self.recipient_token_account.as_ref(),
+                    vault_a: swap_account(
+                        &self.stable_pool_vault_a,
+                        BridgeError::InvalidStablePool,
+                    )?,
+                    vault_b: swap_account(
+                        &self.stable_pool_vault_b,
+                        BridgeError::InvalidStablePool,
+                    )?,
+                },
+                amount,
+                min_out,
+                now,
+                &[&seeds],
+            );
+        }
+        let whirlpool = swap_account(&self.whirlpool, BridgeError::InvalidWhirlpool)?;
+        require_keys_eq!(
+            *whirlpool.key,
+            output_token.swap_pools.whirlpool,
+            BridgeError::InvalidWhirlpool
+        );
+        orca::swap_exact_input(
+            WhirlpoolSwap {
+                whirlpool_program: swap_account(
+                    &self.whirlpool_program,
+                    BridgeError::InvalidWhirlpool,
+                )?,
+                token_program: &self.token_program,
+                token_authority: &self.vault_authority,
+                whirlpool,
+                source: self.vault.as_ref(),
+                destination: self.recipient_token_account.as_ref(),
+                token_vault_a: swap_account(
+                    &self.whirlpool_vault_a,
+                    BridgeError::InvalidWhirlpool,
+                )?,
+                token_vault_b: swap_account(
+                    &self.whirlpool_vault_b,
+                    BridgeError::InvalidWhirlpool,
+                )?,
+                tick_arrays: [
+                    swap_account(&self.tick_array_0, BridgeError::InvalidWhirlpool)?,
+                    swap_account(&self.tick_array_1, BridgeError::InvalidWhirlpool)?,
+                    swap_account(&self.tick_array_2, BridgeError::InvalidWhirlpool)?,
+                ],
+                oracle: swap_account(&self.whirlpool_oracle, BridgeError::InvalidWhirlpool)?,
+            },
+            amount,
+            min_out,
+            max_slippage_bps,
+            &[&seeds],
+        )
+    }
+}
+
+fn swap_account<'a, 'info>(
+    account: &'a Option<UncheckedAccount<'info>>,
+    missing: BridgeError,
+) -> Result<&'a AccountInfo<'info>> {
+    account.as_deref().ok_or_else(|| error!(missing))
+}
+
+pub fn handler(ctx: Context<Withdraw>, vaa: Vec<u8>) -> Result<()> {
+    require!(!vaa.is_empty(), BridgeError::EmptyVaa);
+    ctx.accounts.config.circuit_breaker.check_closed()?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let vaa = ParsedVaa::parse(&vaa)?;
+    vaa.verify(&ctx.accounts.guardian_set, now)?;
+    require!(
+        ctx.accounts
+            .foreign_emitter
+            .is_registered(vaa.emitter_chain, &vaa.emitter_address),
+        BridgeError::UnknownEmitter
+    );
+
+    let message = TransferMessage::decode(&vaa.payload)?;
+    ctx.accounts
+        .claim
+        .mark_processed(message.transfer_id, now, ctx.bumps.claim)?;
+    require!(
+        message.source_chain != SOLANA_CHAIN_ID && message.source_chain == vaa.emitter_chain,
+        BridgeError::InvalidSourceChain
+    );
+    require!(
+        message.recipient.len() == 32,
+        BridgeError::InvalidAddressLength
+    );
+
+    let token = &ctx.accounts.supported_token;
+    token.check_enabled()?;
+    security::check_not_paused(
+        TransferDirection::Inbound,
+        &ctx.accounts.config.paused,
+        &token.paused,
+        &ctx.accounts.foreign_emitter.paused,
+    )?;
+    token
+        .price_guard
+        .check(ctx.accounts.price_feed.as_deref(), now)?;
+    ctx.accounts.config.check_transfer_amount(message.amount_usd)?;
+    token.check_amount_usd(message.amount_usd)?;
+    let amount = token.from_usd(message.amount_usd)?;
+
+    // The sender named the token to be paid in; anything else is swapped
+    // into it from the vault.
+    require!(
+        ctx.accounts.recipient_token_account.mint.to_bytes() == message.destination_token,
+        BridgeError::DestinationTokenMismatch
+    );
+    let swap = ctx.accounts.recipient_token_account.mint != ctx.accounts.mint.key();
+    let payout_token = if swap {
+        ctx.accounts
+            .output_token
+            .as_deref()
+            .ok_or(BridgeError::InvalidWhirlpool)?
+    } else {
+        token
+    };
+    // Converting into the payout mint's precision can round the value below
+    // the sender's floor; such transfers are refunded instead of settled.
+    // The vault mint, which the submitter picks, plays no part here.
+    let settled_usd = payout_token.to_usd(payout_token.from_usd(message.amount_usd)?)?;
+    let below_floor = settled_usd < message.min_destination_amount;
+    if !below_floor {
+        ctx.accounts.rate_limit.bucket.consume(message.amount_usd, now)?;
+        ctx.accounts.config.daily_volume.consume(message.amount_usd, now)?;
+    }
+
+    let record = &mut ctx.accounts.transfer_record;
+    record.transfer_id = message.transfer_id;
+    record.direction = TransferDirection::Inbound;
+    record.status = TransferStatus::Delivered;
+    record.mint = ctx.accounts.mint.key();
+    record.local_account = Pubkey::try_from(message.recipient.as_slice())
+        .map_err(|_| error!(BridgeError::InvalidAddressLength))?;
+    record.counterparty = to_universal_address(&message.sender)?;
+    record.amount = amount;
+    record.fee = 0;
+    record.amount_usd = message.amount_usd;
+    record.source_chain = message.source_chain;
+    record.destination_chain = SOLANA_CHAIN_ID;
+    record.created_at = now;
+    record.updated_at = now;
+    record.bump = ctx.bumps.transfer_record;
+
+    require_keys_eq!(
+        ctx.accounts.recipient_token_account.owner,
+        record.local_account,
+        BridgeError::RecipientMismatch
+    );
+
+    if below_floor {
+        require!(
+            ctx.accounts.pending_release.is_none(),
+            BridgeError::PendingReleaseNotNeeded
+        );
+        record.transition(TransferStatus::Refundable, now)?;
+        emit!(TransferRefundable {
+            transfer_id: record.transfer_id,
+            settled_usd,
+            min_destination_amount: message.min_destination_amount,
+        });
+        msg!(
+            "Transfer worth {} is below its floor of {}; refundable",
+            settled_usd,
+            message.min_destination_amount
+        );
+        return Ok(());
+    }
+
+    if ctx.accounts.config.requires_release_delay(record.amount_usd) {
+        let pending = ctx
+            .accounts
+            .pending_release
+            .as_mut()
+            .ok_or(BridgeError::PendingReleaseRequired)?;
+        require!(!swap, BridgeError::SwapNotAllowed);
+        pending.transfer_id = record.transfer_id;
+        pending.mint = record.mint;
+        pending.destination = ctx.accounts.recipient_token_account.key();
+        pending.amount = record.amount;
+        pending.amount_usd = record.amount_usd;
+        pending.payer = ctx.accounts.payer.key();
+        pending.created_at = now;
+        pending.unlock_at = now
+            .checked_add(ctx.accounts.config.release_delay)
+            .ok_or(BridgeError::MathOverflow)?;
+        pending.frozen = false;
+        pending.frozen_by = Pubkey::default();
+        pending.bump = ctx.bumps.pending_release;
+        ctx.accounts.ledger.hold(pending.amount)?;
+
+        emit!(ReleaseQueued {
+            transfer_id: pending.transfer_id,
+            mint: pending.mint,
+            amount: pending.amount,
+            unlock_at: pending.unlock_at,
+        });
+        msg!(
+            "Release of {} to {} held until {}",
+            pending.amount,
+            record.local_account,
+            pending.unlock_at
+        );
+        return Ok(());
+    }
+
+    require!(
+        ctx.accounts.pending_release.is_none(),
+        BridgeError::PendingReleaseNotNeeded
+    );
+    let (amount, amount_usd) = (record.amount, record.amount_usd);
+    let received = if swap {
+        ctx.accounts
+            .swap_from_vault(amount, amount_usd, message.min_destination_amount, now)?
+    } else {
+        release_from_vault(
+            &ctx.accounts.config,
+            &ctx.accounts.vault,
+            &ctx.accounts.recipient_token_account,
+            &ctx.accounts.vault_authority,
+            &ctx.accounts.token_program,
+            amount,
+        )?;
+        amount
+    };
+    ctx.accounts
+        .transfer_record
+        .transition(TransferStatus::Completed, now)?;
+    ctx.accounts.ledger.record_release(amount)?;
+    ctx.accounts.config.circuit_breaker.record_outflow(
+        &mut ctx.accounts.outflow_window,
+        amount_usd,
+        now,
+    )?;
+
+    msg!(
+        "Released {} ({} received) to {} for transfer from chain {}",
+        amount,
+        received,
+        ctx.accounts.recipient_token_account.owner,
+        message.source_chain
+    );
+    Ok(())
+}
+
+/// Moves `amount` out of a vault, signing as the vault authority PDA.
+pub fn release_from_vault<'info>(
+    config: &Account<'info, BridgeConfig>,
+    vault: &Account<'info, TokenAccount>,
+    destination: &Account<'info, TokenAccount>,
+    vault_authority: &UncheckedAccount<'info>,
+    token_program: &Program<'info, Token>,
+    amount: u64,
+) -> Result<()> {
+    vault
+        .amount
+        .checked_sub(amount)
+        .ok_or(BridgeError::InsufficientVaultBalance)?;
+
+    let seeds = config.vault_authority_seeds();
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            Transfer {
+                from: vault.to_account_info(),
+                to: destination.to_account_info(),
+                authority: vault_authority.to_account_info(),
+            },
+            &[&seeds],
+        ),
+        amount,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::{bpf_loader, program_pack::Pack};
+    use anchor_spl::token::spl_token;
+
+    fn token_account(mint: Pubkey, owner: Pubkey, amount: u64) -> Vec<u8> {
+        let mut data = vec![0u8; spl_token::state::Account::LEN];
+        spl_token::state::Account {
+            mint,
+            owner,
+            amount,
+            state: spl_token::state::AccountState::Initialized,
+            ..Default::default()
+        }
+        .pack_into_slice(&mut data);
+        data
+    }
+
+    #[test]
+    fn release_cannot_overdraw_the_vault() {
+        let (authority_key, bump) =
+            Pubkey::find_program_address(&[BridgeConfig::VAULT_AUTHORITY_SEED], &crate::ID);
+        let mut config_data = Vec::new();
+        BridgeConfig {
+            vault_authority_bump: bump,
+            ..Default::default()
+        }
+        .try_serialize(&mut config_data)
+        .unwrap();
+        let mint = Pubkey::new_unique();
+        let mut data = [
+            config_data,
+            token_account(mint, authority_key, 100),
+            token_account(mint, Pubkey::new_unique(), 0),
+            Vec::new(),
+            Vec::new(),
+        ];
+        let keys = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            authority_key,
+            spl_token::ID,
+        ];
+        let owners = [
+            crate::ID,
+            spl_token::ID,
+            spl_token::ID,
+            System::id(),
+            bpf_loader::ID,
+        ];
+        let mut lamports = [1_000_000u64; 5];
+        let infos: Vec<AccountInfo> = keys
+            .iter()
+            .zip(lamports.iter_mut())
+            .zip(data.iter_mut())
+            .zip(owners.iter())
+            .enumerate()
+            .map(|(i, (((key, lamports), data), owner))| {
+                AccountInfo::new(key, false, true, lamports, data, owner, i == 4, 0)
+            })
+            .collect();
+
+        let vault = Account::<TokenAccount>::try_from(&infos[1]).unwrap();
+        // Fails before the transfer is attempted, leaving the vault intact.
+        let error = release_from_vault(
+            &Account::try_from(&infos[0]).unwrap(),
+            &vault,
+            &Account::try_from(&infos[2]).unwrap(),
+            &UncheckedAccount::try_from(&infos[3]),
+            &Program::try_from(&infos[4]).unwrap(),
+            101,
+        )
+        .unwrap_err();
+        assert_eq!(error, BridgeError::InsufficientVaultBalance.into());
+        assert_eq!(vault.amount, 100);
+    }
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::errors::BridgeError;
+use crate::events::FeesWithdrawn;
+use crate::instructions::withdraw::release_from_vault;
+use crate::security::{has_role, Role, RoleMember};
+use crate::state::{BridgeConfig, SupportedToken, TokenLedger};
+
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = config.bump,
+        constraint = has_role(&config, &authority.key(), role_member.as_deref(), Role::FeeManager)
+            @ BridgeError::Unauthorized
+    )]
+    pub config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [RoleMember::SEED, &[role_member.role as u8], authority.key().as_ref()],
+        bump = role_member.bump
+    )]
+    pub role_member: Option<Account<'info, RoleMember>>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        seeds = [SupportedToken::SEED, mint.key().as_ref()],
+        bump = supported_token.bump
+    )]
+    pub supported_token: Account<'info, SupportedToken>,
+
+    #[account(
+        mut,
+        seeds = [TokenLedger::SEED, mint.key().as_ref()],
+        bump = ledger.bump
+    )]
+    pub ledger: Account<'info, TokenLedger>,
+
+    #[account(
+        mut,
+        seeds = [BridgeConfig::VAULT_SEED, mint.key().as_ref()],
+        bump = supported_token.vault_bump,
+        token::mint = mint,
+        token::authority = vault_authority
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// CHECK: Data-less PDA that owns the vault token accounts.
+    #[account(
+        seeds = [BridgeConfig::VAULT_AUTHORITY_SEED],
+        bump = config.vault_authority_bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(mut, token::mint = mint)]
+    pub destination: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<WithdrawFees>, amount: u64) -> Result<()> {
+    ctx.accounts.ledger.record_fee_withdrawal(amount)?;
+    release_from_vault(
+        &ctx.accounts.config,
+        &ctx.accounts.vault,
+        &ctx.accounts.destination,
+        &ctx.accounts.vault_authority,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+
+    let mint = ctx.accounts.mint.key();
+    emit!(FeesWithdrawn {
+        mint,
+        amount,
+        destination: ctx.accounts.destination.key(),
+        authority: ctx.accounts.authority.key(),
+    });
+    msg!("Withdrew {} in fees of {}", amount, mint);
+    Ok(())
+}
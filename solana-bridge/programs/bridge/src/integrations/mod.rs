@@ -0,0 +1,9 @@
+pub mod orca;
+pub mod pyth;
+pub mod stable_swap;
+pub mod wormhole;
+
+pub use orca::*;
+pub use pyth::*;
+pub use stable_swap::*;
+pub use wormhole::*;
@@ -0,0 +1,394 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
+use anchor_spl::token::accessor;
+
+use crate::errors::BridgeError;
+use crate::state::MAX_BPS;
+
+/// Orca Whirlpool program, `whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc`.
+pub const WHIRLPOOL_PROGRAM_ID: Pubkey = Pubkey::new_from_array([
+    14, 3, 104, 95, 142, 144, 144, 83, 228, 88, 18, 28, 102, 245, 167, 106, 237, 199, 112, 106,
+    161, 28, 130, 248, 170, 149, 42, 143, 43, 120, 121, 169,
+]);
+
+/// Seed of a pool's tick array PDAs, followed by the pool and start index.
+pub const TICK_ARRAY_SEED: &[u8] = b"tick_array";
+
+/// Seed of a pool's oracle PDA.
+pub const ORACLE_SEED: &[u8] = b"oracle";
+
+/// Ticks held by one tick array.
+pub const TICK_ARRAY_SIZE: i32 = 88;
+
+/// Lowest sqrt price (Q64.64) a pool can reach.
+pub const MIN_SQRT_PRICE_X64: u128 = 4_295_048_016;
+
+/// Highest sqrt price (Q64.64) a pool can reach.
+pub const MAX_SQRT_PRICE_X64: u128 = 79_226_673_515_401_279_992_447_579_055;
+
+/// Anchor discriminator of the Whirlpool `swap` instruction.
+const SWAP_DISCRIMINATOR: [u8; 8] = [248, 198, 158, 145, 225, 117, 135, 200];
+
+// Offsets into a Whirlpool account's data, counted from its start, so they
+// include the 8-byte discriminator. The fields skipped are the config key
+// (8), bump (40), tick spacing seed (43), fee rates (45, 47), liquidity
+// (49), protocol fees owed (85, 93) and fee growth of token A (165).
+const TICK_SPACING_OFFSET: usize = 41;
+const SQRT_PRICE_OFFSET: usize = 65;
+const TICK_CURRENT_INDEX_OFFSET: usize = 81;
+const TOKEN_MINT_A_OFFSET: usize = 101;
+const TOKEN_VAULT_A_OFFSET: usize = 133;
+const TOKEN_MINT_B_OFFSET: usize = 181;
+const TOKEN_VAULT_B_OFFSET: usize = 213;
+
+/// Bytes needed to read every field used here.
+const WHIRLPOOL_MIN_LEN: usize = TOKEN_VAULT_B_OFFSET + 32;
+
+/// Pool state needed to route a swap.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Whirlpool {
+    pub tick_spacing: u16,
+    pub sqrt_price: u128,
+    pub tick_current_index: i32,
+    pub token_mint_a: Pubkey,
+    pub token_vault_a: Pubkey,
+    pub token_mint_b: Pubkey,
+    pub token_vault_b: Pubkey,
+}
+
+impl Whirlpool {
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        require_keys_eq!(
+            *account.owner,
+            WHIRLPOOL_PROGRAM_ID,
+            BridgeError::InvalidWhirlpool
+        );
+        Self::parse(&account.try_borrow_data()?)
+    }
+
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        require!(
+            data.len() >= WHIRLPOOL_MIN_LEN,
+            BridgeError::InvalidWhirlpool
+        );
+        let tick_spacing = u16::from_le_bytes(read(data, TICK_SPACING_OFFSET));
+        require!(tick_spacing > 0, BridgeError::InvalidWhirlpool);
+        Ok(Self {
+            tick_spacing,
+            sqrt_price: u128::from_le_bytes(read(data, SQRT_PRICE_OFFSET)),
+            tick_current_index: i32::from_le_bytes(read(data, TICK_CURRENT_INDEX_OFFSET)),
+            token_mint_a: Pubkey::new_from_array(read(data, TOKEN_MINT_A_OFFSET)),
+            token_vault_a: Pubkey::new_from_array(read(data, TOKEN_VAULT_A_OFFSET)),
+            token_mint_b: Pubkey::new_from_array(read(data, TOKEN_MINT_B_OFFSET)),
+            token_vault_b: Pubkey::new_from_array(read(data, TOKEN_VAULT_B_OFFSET)),
+        })
+    }
+
+    /// Direction of a swap from `input_mint` into `output_mint`: `true` if
+    /// it sells token A for token B.
+    pub fn direction(&self, input_mint: &Pubkey, output_mint: &Pubkey) -> Result<bool> {
+        if (self.token_mint_a, self.token_mint_b) == (*input_mint, *output_mint) {
+            Ok(true)
+        } else if (self.token_mint_b, self.token_mint_a) == (*input_mint, *output_mint) {
+            Ok(false)
+        } else {
+            err!(BridgeError::InvalidWhirlpool)
+        }
+    }
+
+    /// The three tick arrays a swap walks through, in the order the
+    /// Whirlpool program expects them.
+    pub fn tick_arrays(&self, whirlpool: &Pubkey, a_to_b: bool) -> [Pubkey; 3] {
+        // Swapping B to A moves the price up; starting one tick higher keeps
+        // a pool sitting on an array boundary from beginning one array short.
+        let tick = if a_to_b {
+            self.tick_current_index
+        } else {
+            self.tick_current_index + self.tick_spacing as i32
+        };
+        let step = if a_to_b { -1 } else { 1 };
+        [0, step, 2 * step].map(|offset| {
+            tick_array_address(
+                whirlpool,
+                tick_array_start_index(tick, self.tick_spacing, offset),
+            )
+        })
+    }
+}
+
+/// Start index of the tick array `offset` arrays away from the one holding
+/// `tick`.
+pub fn tick_array_start_index(tick: i32, tick_spacing: u16, offset: i32) -> i32 {
+    let span = tick_spacing as i32 * TICK_ARRAY_SIZE;
+    (tick.div_euclid(span) + offset) * span
+}
+
+pub fn tick_array_address(whirlpool: &Pubkey, start_index: i32) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            TICK_ARRAY_SEED,
+            whirlpool.as_ref(),
+            start_index.to_string().as_bytes(),
+        ],
+        &WHIRLPOOL_PROGRAM_ID,
+    )
+    .0
+}
+
+pub fn oracle_address(whirlpool: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[ORACLE_SEED, whirlpool.as_ref()], &WHIRLPOOL_PROGRAM_ID).0
+}
+
+/// Furthest the pool's sqrt price may move during a swap so that its price
+/// moves by at most `max_slippage_bps`.
+pub fn sqrt_price_limit(sqrt_price: u128, a_to_b: bool, max_slippage_bps: u16) -> u128 {
+    let bps = max_slippage_bps.min(MAX_BPS) as u128;
+    let ratio = if a_to_b {
+        MAX_BPS as u128 - bps
+    } else {
+        MAX_BPS as u128 + bps
+    };
+    // sqrt(ratio / 10^4), with 6 decimals.
+    let factor = isqrt(ratio * 100_000_000);
+    let limit = sqrt_price.saturating_mul(factor) / 1_000_000;
+    limit.clamp(MIN_SQRT_PRICE_X64, MAX_SQRT_PRICE_X64)
+}
+
+fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    let mut x = n;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+fn read<const N: usize>(data: &[u8], offset: usize) -> [u8; N] {
+    data[offset..offset + N].try_into().unwrap()
+}
+
+#[derive(AnchorSerialize)]
+struct SwapData {
+    amount: u64,
+    other_amount_threshold: u64,
+    sqrt_price_limit: u128,
+    amount_specified_is_input: bool,
+    a_to_b: bool,
+}
+
+/// Accounts of the Whirlpool `swap` instruction. `source` and `destination`
+/// are the caller's accounts of the input and output mint.
+pub struct WhirlpoolSwap<'a, 'info> {
+    pub whirlpool_program: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub token_authority: &'a AccountInfo<'info>,
+    pub whirlpool: &'a AccountInfo<'info>,
+    pub source: &'a AccountInfo<'info>,
+    pub destination: &'a AccountInfo<'info>,
+    pub token_vault_a: &'a AccountInfo<'info>,
+    pub token_vault_b: &'a AccountInfo<'info>,
+    pub tick_arrays: [&'a AccountInfo<'info>; 3],
+    pub oracle: &'a AccountInfo<'info>,
+}
+
+/// Sells exactly `amount_in` of `source` for at least
+/// `other_amount_threshold` into `destination`, stopping at
+/// `sqrt_price_limit`. Checks every account against the pool, and the
+/// balance changes after the swap. Returns the amount received.
+pub fn swap_exact_input(
+    accounts: WhirlpoolSwap,
+    amount_in: u64,
+    other_amount_threshold: u64,
+    max_slippage_bps: u16,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<u64> {
+    require_keys_eq!(
+        *accounts.whirlpool_program.key,
+        WHIRLPOOL_PROGRAM_ID,
+        BridgeError::InvalidWhirlpool
+    );
+    let pool = Whirlpool::load(accounts.whirlpool)?;
+    let a_to_b = pool.direction(
+        &accessor::mint(accounts.source)?,
+        &accessor::mint(accounts.destination)?,
+    )?;
+    let tick_arrays = pool.tick_arrays(accounts.whirlpool.key, a_to_b);
+    require!(
+        *accounts.token_vault_a.key == pool.token_vault_a
+            && *accounts.token_vault_b.key == pool.token_vault_b
+            && *accounts.oracle.key == oracle_address(accounts.whirlpool.key)
+            && accounts
+                .tick_arrays
+                .iter()
+                .zip(&tick_arrays)
+                .all(|(info, expected)| info.key == expected),
+        BridgeError::InvalidWhirlpool
+    );
+
+    let (owner_a, owner_b) = if a_to_b {
+        (accounts.source, accounts.destination)
+    } else {
+        (accounts.destination, accounts.source)
+    };
+    let data = SwapData {
+        amount: amount_in,
+        other_amount_threshold,
+        sqrt_price_limit: sqrt_price_limit(pool.sqrt_price, a_to_b, max_slippage_bps),
+        amount_specified_is_input: true,
+        a_to_b,
+    };
+    let mut ix_data = SWAP_DISCRIMINATOR.to_vec();
+    ix_data.extend_from_slice(&data.try_to_vec()?);
+    let ix = Instruction {
+        program_id: WHIRLPOOL_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*accounts.token_program.key, false),
+            AccountMeta::new_readonly(*accounts.token_authority.key, true),
+            AccountMeta::new(*accounts.whirlpool.key, false),
+            AccountMeta::new(*owner_a.key, false),
+            AccountMeta::new(*accounts.token_vault_a.key, false),
+            AccountMeta::new(*owner_b.key, false),
+            AccountMeta::new(*accounts.token_vault_b.key, false),
+            AccountMeta::new(*accounts.tick_arrays[0].key, false),
+            AccountMeta::new(*accounts.tick_arrays[1].key, false),
+            AccountMeta::new(*accounts.tick_arrays[2].key, false),
+            AccountMeta::new(*accounts.oracle.key, false),
+        ],
+        data: ix_data,
+    };
+
+    let source_before = accessor::amount(accounts.source)?;
+    let destination_before = accessor::amount(accounts.destination)?;
+    invoke_signed(
+        &ix,
+        &[
+            accounts.token_program.clone(),
+            accounts.token_authority.clone(),
+            accounts.whirlpool.clone(),
+            owner_a.clone(),
+            accounts.token_vault_a.clone(),
+            owner_b.clone(),
+            accounts.token_vault_b.clone(),
+            accounts.tick_arrays[0].clone(),
+            accounts.tick_arrays[1].clone(),
+            accounts.tick_arrays[2].clone(),
+            accounts.oracle.clone(),
+        ],
+        signer_seeds,
+    )?;
+
+    // The pool is outside our control, so trust only the balances.
+    let spent = source_before.saturating_sub(accessor::amount(accounts.source)?);
+    let received = accessor::amount(accounts.destination)?.saturating_sub(destination_before);
+    require!(spent == amount_in, BridgeError::InvalidWhirlpool);
+    require!(
+        received >= other_amount_threshold,
+        BridgeError::SlippageExceeded
+    );
+    Ok(received)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn program_id_matches() {
+        assert_eq!(
+            WHIRLPOOL_PROGRAM_ID,
+            Pubkey::from_str("whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc").unwrap()
+        );
+    }
+
+    fn pool(tick_current_index: i32) -> Whirlpool {
+        Whirlpool {
+            tick_spacing: 1,
+            sqrt_price: 1 << 64,
+            tick_current_index,
+            token_mint_a: Pubkey::new_unique(),
+            token_vault_a: Pubkey::new_unique(),
+            token_mint_b: Pubkey::new_unique(),
+            token_vault_b: Pubkey::new_unique(),
+        }
+    }
+
+    #[test]
+    fn parses_whirlpool_layout() {
+        let expected = pool(-5);
+        let mut data = vec![0u8; 653];
+        data[TICK_SPACING_OFFSET..][..2].copy_from_slice(&1u16.to_le_bytes());
+        data[SQRT_PRICE_OFFSET..][..16].copy_from_slice(&(1u128 << 64).to_le_bytes());
+        data[TICK_CURRENT_INDEX_OFFSET..][..4].copy_from_slice(&(-5i32).to_le_bytes());
+        data[TOKEN_MINT_A_OFFSET..][..32].copy_from_slice(expected.token_mint_a.as_ref());
+        data[TOKEN_VAULT_A_OFFSET..][..32].copy_from_slice(expected.token_vault_a.as_ref());
+        data[TOKEN_MINT_B_OFFSET..][..32].copy_from_slice(expected.token_mint_b.as_ref());
+        data[TOKEN_VAULT_B_OFFSET..][..32].copy_from_slice(expected.token_vault_b.as_ref());
+        assert_eq!(Whirlpool::parse(&data).unwrap(), expected);
+
+        assert_eq!(
+            Whirlpool::parse(&data[..WHIRLPOOL_MIN_LEN - 1]).unwrap_err(),
+            BridgeError::InvalidWhirlpool.into()
+        );
+    }
+
+    #[test]
+    fn tick_array_starts_align_to_span() {
+        assert_eq!(tick_array_start_index(0, 64, 0), 0);
+        assert_eq!(tick_array_start_index(5_631, 64, 0), 0);
+        assert_eq!(tick_array_start_index(5_632, 64, 0), 5_632);
+        assert_eq!(tick_array_start_index(-1, 64, 0), -5_632);
+        assert_eq!(tick_array_start_index(-1, 64, -2), -16_896);
+        assert_eq!(tick_array_start_index(100, 1, 1), 176);
+    }
+
+    #[test]
+    fn tick_arrays_follow_swap_direction() {
+        let key = Pubkey::new_unique();
+        let p = pool(0);
+        let down = p.tick_arrays(&key, true);
+        assert_eq!(down[0], tick_array_address(&key, 0));
+        assert_eq!(down[1], tick_array_address(&key, -88));
+        assert_eq!(down[2], tick_array_address(&key, -176));
+
+        // One tick below a boundary, B to A already starts in the next array.
+        let up = pool(87).tick_arrays(&key, false);
+        assert_eq!(up[0], tick_array_address(&key, 88));
+        assert_eq!(up[2], tick_array_address(&key, 264));
+    }
+
+    #[test]
+    fn direction_matches_pool_mints() {
+        let p = pool(0);
+        assert!(p.direction(&p.token_mint_a, &p.token_mint_b).unwrap());
+        assert!(!p.direction(&p.token_mint_b, &p.token_mint_a).unwrap());
+        assert!(p.direction(&p.token_mint_a, &Pubkey::new_unique()).is_err());
+    }
+
+    #[test]
+    fn price_limit_bounds_slippage() {
+        let one = 1u128 << 64;
+        let down = sqrt_price_limit(one, true, 100);
+        let up = sqrt_price_limit(one, false, 100);
+        // sqrt(0.99) and sqrt(1.01), to 6 decimals.
+        assert_eq!(down, one * 994_987 / 1_000_000);
+        assert_eq!(up, one * 1_004_987 / 1_000_000);
+
+        assert_eq!(
+            sqrt_price_limit(MIN_SQRT_PRICE_X64, true, 100),
+            MIN_SQRT_PRICE_X64
+        );
+        assert_eq!(
+            sqrt_price_limit(MAX_SQRT_PRICE_X64, false, 100),
+            MAX_SQRT_PRICE_X64
+        );
+        assert_eq!(sqrt_price_limit(one, true, MAX_BPS), MIN_SQRT_PRICE_X64);
+    }
+}
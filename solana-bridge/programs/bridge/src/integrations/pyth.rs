@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::state::USD_DECIMALS;
+
+/// Magic number at the start of every Pyth account.
+pub const PYTH_MAGIC: u32 = 0xa1b2_c3d4;
+
+/// Account layout version this reader understands.
+pub const PYTH_VERSION: u32 = 2;
+
+/// `atype` of a price account.
+const PRICE_ACCOUNT_TYPE: u32 = 3;
+
+/// `agg.status` of a price that is currently trading.
+const STATUS_TRADING: u32 = 1;
+
+// Offsets into a Pyth v2 price account.
+const MAGIC_OFFSET: usize = 0;
+const VERSION_OFFSET: usize = 4;
+const ACCOUNT_TYPE_OFFSET: usize = 8;
+const EXPONENT_OFFSET: usize = 20;
+const TIMESTAMP_OFFSET: usize = 96;
+const AGG_PRICE_OFFSET: usize = 208;
+const AGG_CONF_OFFSET: usize = 216;
+const AGG_STATUS_OFFSET: usize = 224;
+
+/// Bytes needed to read every field used here.
+pub const PRICE_ACCOUNT_MIN_LEN: usize = AGG_STATUS_OFFSET + 4;
+
+/// Aggregate price read from a Pyth price account.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PriceFeed {
+    /// Price in units of `10^exponent`.
+    pub price: i64,
+    /// Confidence interval, in the same units as `price`.
+    pub conf: u64,
+    pub exponent: i32,
+    /// Unix time the aggregate was published.
+    pub publish_time: i64,
+    pub trading: bool,
+}
+
+impl PriceFeed {
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        Self::parse(&account.try_borrow_data()?)
+    }
+
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        require!(
+            data.len() >= PRICE_ACCOUNT_MIN_LEN
+                && read_u32(data, MAGIC_OFFSET) == PYTH_MAGIC
+                && read_u32(data, VERSION_OFFSET) == PYTH_VERSION
+                && read_u32(data, ACCOUNT_TYPE_OFFSET) == PRICE_ACCOUNT_TYPE,
+            BridgeError::InvalidPriceFeed
+        );
+        Ok(Self {
+            price: read_u64(data, AGG_PRICE_OFFSET) as i64,
+            conf: read_u64(data, AGG_CONF_OFFSET),
+            exponent: read_u32(data, EXPONENT_OFFSET) as i32,
+            publish_time: read_u64(data, TIMESTAMP_OFFSET) as i64,
+            trading: read_u32(data, AGG_STATUS_OFFSET) == STATUS_TRADING,
+        })
+    }
+
+    /// Price in USD with 6 decimals, rounded down.
+    pub fn price_usd(&self) -> Result<u64> {
+        require!(self.price > 0, BridgeError::InvalidPriceFeed);
+        let shift = self.exponent + USD_DECIMALS as i32;
+        let factor = 10u128
+            .checked_pow(shift.unsigned_abs())
+            .ok_or(BridgeError::InvalidPriceFeed)?;
+        let price = self.price as u128;
+        let scaled = if shift >= 0 {
+            price.checked_mul(factor)
+        } else {
+            price.checked_div(factor)
+        }
+        .ok_or(BridgeError::MathOverflow)?;
+        u64::try_from(scaled).map_err(|_| error!(BridgeError::MathOverflow))
+    }
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
+
+/// Price account bytes laid out as Pyth writes them, for tests.
+#[cfg(test)]
+pub fn mock_price_account(price: i64, exponent: i32, publish_time: i64, trading: bool) -> Vec<u8> {
+    let mut data = vec![0u8; 3_312];
+    data[MAGIC_OFFSET..][..4].copy_from_slice(&PYTH_MAGIC.to_le_bytes());
+    data[VERSION_OFFSET..][..4].copy_from_slice(&PYTH_VERSION.to_le_bytes());
+    data[ACCOUNT_TYPE_OFFSET..][..4].copy_from_slice(&PRICE_ACCOUNT_TYPE.to_le_bytes());
+    data[EXPONENT_OFFSET..][..4].copy_from_slice(&exponent.to_le_bytes());
+    data[TIMESTAMP_OFFSET..][..8].copy_from_slice(&publish_time.to_le_bytes());
+    data[AGG_PRICE_OFFSET..][..8].copy_from_slice(&price.to_le_bytes());
+    data[AGG_CONF_OFFSET..][..8].copy_from_slice(&1_000u64.to_le_bytes());
+    let status = if trading { STATUS_TRADING } else { 0 };
+    data[AGG_STATUS_OFFSET..][..4].copy_from_slice(&status.to_le_bytes());
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_price_account() {
+        let data = mock_price_account(99_980_000, -8, 1_700_000_000, true);
+        let feed = PriceFeed::parse(&data).unwrap();
+        assert_eq!(feed.price, 99_980_000);
+        assert_eq!(feed.conf, 1_000);
+        assert_eq!(feed.exponent, -8);
+        assert_eq!(feed.publish_time, 1_700_000_000);
+        assert!(feed.trading);
+        assert_eq!(feed.price_usd().unwrap(), 999_800);
+    }
+
+    #[test]
+    fn rejects_other_accounts() {
+        let mut data = mock_price_account(1, -8, 0, true);
+        data[ACCOUNT_TYPE_OFFSET] = 2;
+        assert_eq!(
+            PriceFeed::parse(&data).unwrap_err(),
+            BridgeError::InvalidPriceFeed.into()
+        );
+        assert!(PriceFeed::parse(&[0u8; 64]).is_err());
+    }
+
+    #[test]
+    fn scales_any_exponent() {
+        let feed = |price, exponent| PriceFeed {
+            price,
+            conf: 0,
+            exponent,
+            publish_time: 0,
+            trading: true,
+        };
+        assert_eq!(feed(1_000_123, -6).price_usd().unwrap(), 1_000_123);
+        assert_eq!(feed(1, 0).price_usd().unwrap(), 1_000_000);
+        assert_eq!(feed(100_012_345, -8).price_usd().unwrap(), 1_000_123);
+        assert!(feed(-1, -8).price_usd().is_err());
+        assert!(feed(0, -8).price_usd().is_err());
+    }
+}
@@ -0,0 +1,214 @@
+use amm::math::SwapCalculation;
+use amm::state::Pool;
+use anchor_lang::prelude::*;
+use anchor_spl::token::accessor;
+
+use crate::errors::BridgeError;
+
+/// A StableSwap pool of the amm program, read for quoting and routing.
+#[derive(Clone)]
+pub struct StablePool {
+    pub address: Pubkey,
+    pub pool: Pool,
+}
+
+impl StablePool {
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        require_keys_eq!(*account.owner, amm::ID, BridgeError::InvalidStablePool);
+        let data = account.try_borrow_data()?;
+        let pool = Pool::try_deserialize(&mut &data[..])
+            .map_err(|_| error!(BridgeError::InvalidStablePool))?;
+        Ok(Self {
+            address: account.key(),
+            pool,
+        })
+    }
+
+    /// A in effect at `now`, following any ramp the amm authority has started.
+    pub fn current_amp(&self, now: i64) -> u64 {
+        self.pool.current_amp(now)
+    }
+
+    /// Quotes selling `amount_in` of `input_mint` for `output_mint`, at the
+    /// same A the pool's own `swap` would use at `now`.
+    pub fn quote(
+        &self,
+        input_mint: &Pubkey,
+        output_mint: &Pubkey,
+        amount_in: u64,
+        now: i64,
+    ) -> Result<SwapCalculation> {
+        let a_to_b = self
+            .pool
+            .direction(input_mint, output_mint)
+            .map_err(|_| error!(BridgeError::InvalidStablePool))?;
+        self.pool.quote_swap(amount_in, a_to_b, now)
+    }
+}
+
+/// Accounts of the amm `swap` instruction. `source` and `destination` are
+/// the caller's accounts of the input and output mint.
+pub struct StableSwap<'a, 'info> {
+    pub amm_program: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub token_authority: &'a AccountInfo<'info>,
+    pub pool: &'a AccountInfo<'info>,
+    pub source: &'a AccountInfo<'info>,
+    pub destination: &'a AccountInfo<'info>,
+    pub vault_a: &'a AccountInfo<'info>,
+    pub vault_b: &'a AccountInfo<'info>,
+}
+
+/// Sells exactly `amount_in` of `source` for at least `min_amount_out` into
+/// `destination`. Quotes at the pool's effective A first, so a pool that
+/// cannot meet the floor is rejected before the CPI. Returns the amount
+/// received.
+pub fn stable_swap_exact_input(
+    accounts: StableSwap,
+    amount_in: u64,
+    min_amount_out: u64,
+    now: i64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<u64> {
+    require_keys_eq!(
+        *accounts.amm_program.key,
+        amm::ID,
+        BridgeError::InvalidStablePool
+    );
+    let pool = StablePool::load(accounts.pool)?;
+    require!(
+        *accounts.vault_a.key == pool.pool.vault_a && *accounts.vault_b.key == pool.pool.vault_b,
+        BridgeError::InvalidStablePool
+    );
+    let quote = pool.quote(
+        &accessor::mint(accounts.source)?,
+        &accessor::mint(accounts.destination)?,
+        amount_in,
+        now,
+    )?;
+    if quote.amount_out < min_amount_out {
+        msg!(
+            "Pool {} quotes {}, below the floor of {}",
+            pool.address,
+            quote.amount_out,
+            min_amount_out
+        );
+        return err!(BridgeError::SlippageExceeded);
+    }
+
+    let destination_before = accessor::amount(accounts.destination)?;
+    amm::cpi::swap(
+        CpiContext::new_with_signer(
+            accounts.amm_program.clone(),
+            amm::cpi::accounts::Swap {
+                user: accounts.token_authority.clone(),
+                pool: accounts.pool.clone(),
+                source: accounts.source.clone(),
+                destination: accounts.destination.clone(),
+                vault_a: accounts.vault_a.clone(),
+                vault_b: accounts.vault_b.clone(),
+                token_program: accounts.token_program.clone(),
+            },
+            signer_seeds,
+        ),
+        amount_in,
+        min_amount_out,
+    )?;
+
+    let received = accessor::amount(accounts.destination)?.saturating_sub(destination_before);
+    require!(received >= min_amount_out, BridgeError::SlippageExceeded);
+    Ok(received)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_700_000_000;
+    const DAY: i64 = 86_400;
+
+    fn pool() -> StablePool {
+        StablePool {
+            address: Pubkey::new_unique(),
+            pool: Pool {
+                mint_a: Pubkey::new_unique(),
+                mint_b: Pubkey::new_unique(),
+                vault_a: Pubkey::new_unique(),
+                vault_b: Pubkey::new_unique(),
+                lp_mint: Pubkey::new_unique(),
+                reserve_a: 1_000_000_000_000,
+                reserve_b: 200_000_000_000,
+                initial_amp: 10,
+                target_amp: 100,
+                ramp_start_ts: NOW,
+                ramp_stop_ts: NOW + 2 * DAY,
+                fee_bps: 4,
+                bump: 255,
+            },
+        }
+    }
+
+    #[test]
+    fn quotes_at_the_ramped_amp() {
+        let p = pool();
+        let (a, b) = (p.pool.mint_a, p.pool.mint_b);
+        assert_eq!(p.current_amp(NOW), 10);
+        assert_eq!(p.current_amp(NOW + DAY), 55);
+        assert_eq!(p.current_amp(NOW + 3 * DAY), 100);
+
+        // A higher A flattens the curve, so the imbalanced pool pays more B.
+        let early = p.quote(&a, &b, 1_000_000_000, NOW).unwrap();
+        let late = p.quote(&a, &b, 1_000_000_000, NOW + 2 * DAY).unwrap();
+        assert!(late.amount_out > early.amount_out);
+        assert_eq!(
+            late,
+            amm::math::calculate_swap(1_000_000_000, 1_000_000_000_000, 200_000_000_000, 100, 4)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn rejects_foreign_mints() {
+        let p = pool();
+        assert_eq!(
+            p.quote(&p.pool.mint_a, &Pubkey::new_unique(), 1, NOW)
+                .unwrap_err(),
+            BridgeError::InvalidStablePool.into()
+        );
+    }
+
+    #[test]
+    fn loads_only_amm_pools() {
+        let p = pool();
+        let mut data = Vec::new();
+        p.pool.try_serialize(&mut data).unwrap();
+        let mut lamports = 1_000_000;
+        let account = AccountInfo::new(
+            &p.address,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &amm::ID,
+            false,
+            0,
+        );
+        let loaded = StablePool::load(&account).unwrap();
+        assert_eq!(loaded.address, p.address);
+        assert_eq!(loaded.current_amp(NOW + DAY), 55);
+
+        let mut foreign = account.clone();
+        let owner = Pubkey::new_unique();
+        foreign.owner = &owner;
+        assert_eq!(
+            StablePool::load(&foreign).map(|_| ()).unwrap_err(),
+            BridgeError::InvalidStablePool.into()
+        );
+
+        account.try_borrow_mut_data().unwrap()[0] ^= 1;
+        assert_eq!(
+            StablePool::load(&account).map(|_| ()).unwrap_err(),
+            BridgeError::InvalidStablePool.into()
+        );
+    }
+}
@@ -0,0 +1,181 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+    system_instruction,
+};
+
+use crate::errors::BridgeError;
+
+/// Seed of the core bridge config account, owned by the Wormhole program.
+pub const BRIDGE_SEED: &[u8] = b"Bridge";
+
+/// Seed of the account collecting message fees, owned by the Wormhole program.
+pub const FEE_COLLECTOR_SEED: &[u8] = b"fee_collector";
+
+/// Seed of an emitter's sequence account, owned by the Wormhole program.
+pub const SEQUENCE_SEED: &[u8] = b"Sequence";
+
+/// Index of `post_message` in the core bridge instruction enum.
+const POST_MESSAGE_IX: u8 = 1;
+
+/// Offset of `config.fee` in the core bridge config account: guardian set
+/// index (4), last lamports (8), guardian set expiration time (4).
+const BRIDGE_FEE_OFFSET: usize = 16;
+
+/// Commitment guardians wait for before signing a message.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Finality {
+    Confirmed,
+    Finalized,
+}
+
+#[derive(AnchorSerialize)]
+struct PostMessageData {
+    nonce: u32,
+    payload: Vec<u8>,
+    finality: Finality,
+}
+
+/// Accounts of the core bridge `post_message` instruction, in order.
+pub struct PostMessage<'a, 'info> {
+    pub wormhole_program: &'a AccountInfo<'info>,
+    pub bridge: &'a AccountInfo<'info>,
+    pub message: &'a AccountInfo<'info>,
+    pub emitter: &'a AccountInfo<'info>,
+    pub sequence: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub fee_collector: &'a AccountInfo<'info>,
+    pub clock: &'a AccountInfo<'info>,
+    pub rent: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+/// Current message fee, in lamports, read from the core bridge config.
+pub fn message_fee(bridge: &AccountInfo) -> Result<u64> {
+    let data = bridge.try_borrow_data()?;
+    let fee = data
+        .get(BRIDGE_FEE_OFFSET..BRIDGE_FEE_OFFSET + 8)
+        .ok_or(BridgeError::InvalidWormholeAccount)?;
+    Ok(u64::from_le_bytes(fee.try_into().unwrap()))
+}
+
+/// Instruction data of `post_message`: its index, then the Borsh-encoded
+/// arguments.
+fn post_message_data(nonce: u32, payload: Vec<u8>, finality: Finality) -> Result<Vec<u8>> {
+    let mut data = vec![POST_MESSAGE_IX];
+    PostMessageData {
+        nonce,
+        payload,
+        finality,
+    }
+    .serialize(&mut data)?;
+    Ok(data)
+}
+
+/// Pays the message fee from `payer` and publishes `payload`. `signer_seeds`
+/// must sign for both the emitter and the message account.
+pub fn post_message(
+    accounts: PostMessage,
+    nonce: u32,
+    payload: Vec<u8>,
+    finality: Finality,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let fee = message_fee(accounts.bridge)?;
+    if fee > 0 {
+        invoke(
+            &system_instruction::transfer(accounts.payer.key, accounts.fee_collector.key, fee),
+            &[
+                accounts.payer.clone(),
+                accounts.fee_collector.clone(),
+                accounts.system_program.clone(),
+            ],
+        )?;
+    }
+
+    let data = post_message_data(nonce, payload, finality)?;
+    let ix = Instruction {
+        program_id: accounts.wormhole_program.key(),
+        accounts: vec![
+            AccountMeta::new(accounts.bridge.key(), false),
+            AccountMeta::new(accounts.message.key(), true),
+            AccountMeta::new_readonly(accounts.emitter.key(), true),
+            AccountMeta::new(accounts.sequence.key(), false),
+            AccountMeta::new(accounts.payer.key(), true),
+            AccountMeta::new(accounts.fee_collector.key(), false),
+            AccountMeta::new_readonly(accounts.clock.key(), false),
+            AccountMeta::new_readonly(accounts.rent.key(), false),
+            AccountMeta::new_readonly(accounts.system_program.key(), false),
+        ],
+        data,
+    };
+    invoke_signed(
+        &ix,
+        &[
+            accounts.bridge.clone(),
+            accounts.message.clone(),
+            accounts.emitter.clone(),
+            accounts.sequence.clone(),
+            accounts.payer.clone(),
+            accounts.fee_collector.clone(),
+            accounts.clock.clone(),
+            accounts.rent.clone(),
+            accounts.system_program.clone(),
+        ],
+        signer_seeds,
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_fee_from_bridge_config() {
+        let key = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let mut lamports = 1_000_000;
+        let mut data = vec![0xff; BRIDGE_FEE_OFFSET];
+        data.extend_from_slice(&5_000u64.to_le_bytes());
+        data.extend_from_slice(&[0xff; 8]);
+        let bridge = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+        assert_eq!(message_fee(&bridge).unwrap(), 5_000);
+
+        let mut lamports = 1_000_000;
+        let mut short = vec![0; BRIDGE_FEE_OFFSET + 7];
+        let bridge = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut short,
+            &owner,
+            false,
+            0,
+        );
+        assert_eq!(
+            message_fee(&bridge).unwrap_err(),
+            BridgeError::InvalidWormholeAccount.into()
+        );
+    }
+
+    #[test]
+    fn encodes_post_message() {
+        let data = post_message_data(7, b"hi".to_vec(), Finality::Finalized).unwrap();
+        assert_eq!(data, [1, 7, 0, 0, 0, 2, 0, 0, 0, b'h', b'i', 1]);
+
+        let data = post_message_data(0, Vec::new(), Finality::Confirmed).unwrap();
+        assert_eq!(data, [1, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    }
+}
@@ -1,27 +1,79 @@
 use anchor_lang::prelude::*;
 
+pub mod errors;
+pub mod events;
 pub mod instructions;
+pub mod integrations;
+pub mod message;
+pub mod security;
 pub mod state;
 pub mod verification;
 
 use instructions::*;
+use security::{CircuitBreakerParams, PauseFlags, PriceGuard, Role, TimelockAction};
+use state::{ProposalAccount, SwapPools, TransferDirection};
 
-declare_id!("22222222222222222222222222222222"); // Placeholder
+declare_id!("GDDMwNyyx8uB6zrqwBFHjLLG3TBYk2F8Az4aBqxXUj9q");
 
 #[program]
 pub mod bridge {
     use super::*;
 
-    pub fn initialize_bridge(ctx: Context<InitializeBridge>) -> Result<()> {
-        instructions::initialize::handler(ctx)
+    pub fn initialize_bridge(
+        ctx: Context<InitializeBridge>,
+        params: InitializeBridgeParams,
+    ) -> Result<()> {
+        instructions::initialize::handler(ctx, params)
+    }
+
+    pub fn initialize_vault(ctx: Context<InitializeVault>) -> Result<()> {
+        instructions::initialize_vault::handler(ctx)
+    }
+
+    pub fn set_guardian_set(
+        ctx: Context<SetGuardianSet>,
+        index: u32,
+        keys: Vec<[u8; 20]>,
+    ) -> Result<()> {
+        instructions::set_guardian_set::handler(ctx, index, keys)
+    }
+
+    pub fn register_emitter(
+        ctx: Context<RegisterEmitter>,
+        chain: u16,
+        address: [u8; 32],
+    ) -> Result<()> {
+        instructions::register_emitter::handler(ctx, chain, address)
+    }
+
+    pub fn update_emitter(ctx: Context<UpdateEmitter>, address: [u8; 32]) -> Result<()> {
+        instructions::update_emitter::handler(ctx, address)
+    }
+
+    pub fn add_token(ctx: Context<AddToken>, params: TokenParams) -> Result<()> {
+        instructions::add_token::handler(ctx, params)
+    }
+
+    pub fn update_token(
+        ctx: Context<UpdateToken>,
+        params: TokenParams,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::update_token::handler(ctx, params, enabled)
+    }
+
+    pub fn disable_token(ctx: Context<DisableToken>) -> Result<()> {
+        instructions::disable_token::handler(ctx)
     }
 
     pub fn deposit(
         ctx: Context<Deposit>,
         amount: u64,
+        min_amount_out: u64,
         recipient: [u8; 32],
+        destination_token: [u8; 32],
     ) -> Result<()> {
-        instructions::deposit::handler(ctx, amount, recipient)
+        instructions::deposit::handler(ctx, amount, min_amount_out, recipient, destination_token)
     }
 
     pub fn withdraw(
@@ -30,4 +82,175 @@ pub mod bridge {
     ) -> Result<()> {
         instructions::withdraw::handler(ctx, vaa)
     }
+
+    pub fn is_vaa_consumed(
+        ctx: Context<IsVaaConsumed>,
+        emitter_chain: u16,
+        emitter_address: [u8; 32],
+        sequence: u64,
+    ) -> Result<bool> {
+        instructions::is_vaa_consumed::handler(ctx, emitter_chain, emitter_address, sequence)
+    }
+
+    pub fn configure_circuit_breaker(
+        ctx: Context<ConfigureCircuitBreaker>,
+        params: CircuitBreakerParams,
+    ) -> Result<()> {
+        instructions::configure_circuit_breaker::handler(ctx, params)
+    }
+
+    pub fn reset_circuit_breaker(ctx: Context<ResetCircuitBreaker>) -> Result<()> {
+        instructions::reset_circuit_breaker::handler(ctx)
+    }
+
+    pub fn set_rate_limit(
+        ctx: Context<SetRateLimit>,
+        chain: u16,
+        direction: TransferDirection,
+        capacity: u64,
+        refill_per_second: u64,
+    ) -> Result<()> {
+        instructions::set_rate_limit::handler(ctx, chain, direction, capacity, refill_per_second)
+    }
+
+    pub fn set_bridge_pause(ctx: Context<SetBridgePause>, paused: PauseFlags) -> Result<()> {
+        instructions::set_bridge_pause::handler(ctx, paused)
+    }
+
+    pub fn set_token_pause(ctx: Context<SetTokenPause>, paused: PauseFlags) -> Result<()> {
+        instructions::set_token_pause::handler(ctx, paused)
+    }
+
+    pub fn set_chain_pause(ctx: Context<SetChainPause>, paused: PauseFlags) -> Result<()> {
+        instructions::set_chain_pause::handler(ctx, paused)
+    }
+
+    pub fn grant_role(ctx: Context<GrantRole>, role: Role, member: Pubkey) -> Result<()> {
+        instructions::grant_role::handler(ctx, role, member)
+    }
+
+    pub fn revoke_role(ctx: Context<RevokeRole>) -> Result<()> {
+        instructions::revoke_role::handler(ctx)
+    }
+
+    pub fn renounce_role(ctx: Context<RenounceRole>) -> Result<()> {
+        instructions::renounce_role::handler(ctx)
+    }
+
+    pub fn set_fee(ctx: Context<SetFee>, fee_bps: u16) -> Result<()> {
+        instructions::set_fee::handler(ctx, fee_bps)
+    }
+
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, pending_admin: Pubkey) -> Result<()> {
+        instructions::propose_admin::handler(ctx, pending_admin)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::accept_admin::handler(ctx)
+    }
+
+    pub fn schedule_operation(
+        ctx: Context<ScheduleOperation>,
+        action: TimelockAction,
+    ) -> Result<()> {
+        instructions::schedule_operation::handler(ctx, action)
+    }
+
+    pub fn cancel_operation(ctx: Context<CancelOperation>) -> Result<()> {
+        instructions::cancel_operation::handler(ctx)
+    }
+
+    pub fn set_transfer_limits(
+        ctx: Context<SetTransferLimits>,
+        min_transfer: u64,
+        max_transfer: u64,
+        daily_volume_cap: u64,
+    ) -> Result<()> {
+        instructions::set_transfer_limits::handler(
+            ctx,
+            min_transfer,
+            max_transfer,
+            daily_volume_cap,
+        )
+    }
+
+    pub fn withdraw_fees(ctx: Context<WithdrawFees>, amount: u64) -> Result<()> {
+        instructions::withdraw_fees::handler(ctx, amount)
+    }
+
+    pub fn initialize_council(
+        ctx: Context<InitializeCouncil>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::initialize_council::handler(ctx, members, threshold)
+    }
+
+    pub fn set_council(
+        ctx: Context<SetCouncil>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::set_council::handler(ctx, members, threshold)
+    }
+
+    pub fn propose(
+        ctx: Context<Propose>,
+        accounts: Vec<ProposalAccount>,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        instructions::propose::handler(ctx, accounts, data)
+    }
+
+    pub fn approve(ctx: Context<Approve>) -> Result<()> {
+        instructions::approve::handler(ctx)
+    }
+
+    pub fn execute<'info>(ctx: Context<'_, '_, '_, 'info, Execute<'info>>) -> Result<()> {
+        instructions::execute::handler(ctx)
+    }
+
+    pub fn cancel(ctx: Context<Cancel>) -> Result<()> {
+        instructions::cancel::handler(ctx)
+    }
+
+    pub fn set_release_delay(
+        ctx: Context<SetReleaseDelay>,
+        threshold: u64,
+        delay: i64,
+    ) -> Result<()> {
+        instructions::set_release_delay::handler(ctx, threshold, delay)
+    }
+
+    pub fn veto_release(ctx: Context<VetoRelease>) -> Result<()> {
+        instructions::veto_release::handler(ctx)
+    }
+
+    pub fn resolve_release(ctx: Context<ResolveRelease>, approve: bool) -> Result<()> {
+        instructions::resolve_release::handler(ctx, approve)
+    }
+
+    pub fn finalize_release(ctx: Context<FinalizeRelease>) -> Result<()> {
+        instructions::finalize_release::handler(ctx)
+    }
+
+    pub fn fund_vault(ctx: Context<FundVault>, amount: u64) -> Result<()> {
+        instructions::fund_vault::handler(ctx, amount)
+    }
+
+    pub fn assert_solvency(ctx: Context<AssertSolvency>) -> Result<()> {
+        instructions::assert_solvency::handler(ctx)
+    }
+
+    pub fn set_price_guard(ctx: Context<SetPriceGuard>, guard: PriceGuard) -> Result<()> {
+        instructions::set_price_guard::handler(ctx, guard)
+    }
+
+    pub fn refund_transfer(ctx: Context<RefundTransfer>) -> Result<()> {
+        instructions::refund_transfer::handler(ctx)
+    }
+
+    pub fn set_swap_pools(ctx: Context<SetSwapPools>, pools: SwapPools) -> Result<()> {
+        instructions::set_swap_pools::handler(ctx, pools)
+    }
 }
@@ -0,0 +1,318 @@
+//! Canonical cross-chain transfer message.
+//!
+//! The wire format is fixed-width big-endian so that `MessageCodec.sol` can
+//! decode it with plain `abi.encodePacked`-style slicing:
+//!
+//! | offset       | field                  | size                 |
+//! |--------------|------------------------|----------------------|
+//! | 0            | version                | 1                    |
+//! | 1            | transfer_id            | 32                   |
+//! | 33           | sender_len (S)         | 1 (20 or 32)         |
+//! | 34           | sender                 | S                    |
+//! | 34 + S       | recipient_len (R)      | 1 (20 or 32)         |
+//! | 35 + S       | recipient              | R                    |
+//! | 35 + S + R   | destination_token      | 32                   |
+//! | 67 + S + R   | amount_usd             | 8                    |
+//! | 75 + S + R   | min_destination_amount | 8                    |
+//! | 83 + S + R   | nonce                  | 8                    |
+//! | 91 + S + R   | source_chain           | 2                    |
+//! | 93 + S + R   | timestamp              | 8 (two's complement) |
+//!
+//! A message is `101 + S + R` bytes long. Only [`TransferMessage::VERSION`]
+//! is accepted.
+
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransferMessage {
+    pub version: u8,
+    pub transfer_id: [u8; 32],
+    /// Source chain sender, 20 or 32 bytes.
+    pub sender: Vec<u8>,
+    /// Destination chain recipient, 20 or 32 bytes.
+    pub recipient: Vec<u8>,
+    /// Token the recipient is paid in, as a universal address: a mint on
+    /// Solana, a left-padded token contract elsewhere. All zero on refunds,
+    /// which settle in the token the transfer was deposited in.
+    pub destination_token: [u8; 32],
+    /// Net USD value (6 decimals).
+    pub amount_usd: u64,
+    /// Least USD value (6 decimals) the recipient accepts; below it the
+    /// destination refunds the transfer instead of settling it.
+    pub min_destination_amount: u64,
+    pub nonce: u64,
+    /// Wormhole chain id of the source chain.
+    pub source_chain: u16,
+    pub timestamp: i64,
+}
+
+impl TransferMessage {
+    pub const VERSION: u8 = 2;
+
+    /// Size of every field except the two addresses.
+    const FIXED_LEN: usize = 1 + 32 + 1 + 1 + 32 + 8 + 8 + 8 + 2 + 8;
+
+    /// Reads the transfer id without decoding the whole message, so it can
+    /// be used in account seeds. Malformed input yields an all-zero id and is
+    /// rejected later by the full decode.
+    pub fn peek_transfer_id(bytes: &[u8]) -> [u8; 32] {
+        let mut id = [0u8; 32];
+        if let Some(slice) = bytes.get(1..33) {
+            id.copy_from_slice(slice);
+        }
+        id
+    }
+
+    pub fn encoded_len(&self) -> usize {
+        Self::FIXED_LEN + self.sender.len() + self.recipient.len()
+    }
+
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        check_address(&self.sender)?;
+        check_address(&self.recipient)?;
+        require!(
+            self.version == Self::VERSION,
+            BridgeError::UnsupportedMessageVersion
+        );
+
+        let mut out = Vec::with_capacity(self.encoded_len());
+        out.push(self.version);
+        out.extend_from_slice(&self.transfer_id);
+        out.push(self.sender.len() as u8);
+        out.extend_from_slice(&self.sender);
+        out.push(self.recipient.len() as u8);
+        out.extend_from_slice(&self.recipient);
+        out.extend_from_slice(&self.destination_token);
+        out.extend_from_slice(&self.amount_usd.to_be_bytes());
+        out.extend_from_slice(&self.min_destination_amount.to_be_bytes());
+        out.extend_from_slice(&self.nonce.to_be_bytes());
+        out.extend_from_slice(&self.source_chain.to_be_bytes());
+        out.extend_from_slice(&self.timestamp.to_be_bytes());
+        Ok(out)
+    }
+
+    /// Strict decode: unknown versions, bad address lengths, truncated input
+    /// and trailing bytes are all rejected.
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        let mut reader = Reader::new(bytes);
+
+        let version = reader.read_u8()?;
+        require!(
+            version == Self::VERSION,
+            BridgeError::UnsupportedMessageVersion
+        );
+        let transfer_id = reader.read_array::<32>()?;
+        let sender = reader.read_address()?;
+        let recipient = reader.read_address()?;
+        let destination_token = reader.read_array::<32>()?;
+        let amount_usd = u64::from_be_bytes(reader.read_array()?);
+        let min_destination_amount = u64::from_be_bytes(reader.read_array()?);
+        let nonce = u64::from_be_bytes(reader.read_array()?);
+        let source_chain = u16::from_be_bytes(reader.read_array()?);
+        let timestamp = i64::from_be_bytes(reader.read_array()?);
+        reader.finish()?;
+
+        Ok(Self {
+            version,
+            transfer_id,
+            sender,
+            recipient,
+            destination_token,
+            amount_usd,
+            min_destination_amount,
+            nonce,
+            source_chain,
+            timestamp,
+        })
+    }
+}
+
+fn check_address(address: &[u8]) -> Result<()> {
+    require!(
+        address.len() == 20 || address.len() == 32,
+        BridgeError::InvalidAddressLength
+    );
+    Ok(())
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        require!(self.bytes.len() >= len, BridgeError::TruncatedMessage);
+        let (head, tail) = self.bytes.split_at(len);
+        self.bytes = tail;
+        Ok(head)
+    }
+
+    fn read_u8(&mut self) -> Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut out = [0u8; N];
+        out.copy_from_slice(self.take(N)?);
+        Ok(out)
+    }
+
+    fn read_address(&mut self) -> Result<Vec<u8>> {
+        let len = self.read_u8()? as usize;
+        let address = self.take(len)?;
+        check_address(address)?;
+        Ok(address.to_vec())
+    }
+
+    fn finish(&self) -> Result<()> {
+        require!(self.bytes.is_empty(), BridgeError::TrailingMessageBytes);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> TransferMessage {
+        TransferMessage {
+            version: TransferMessage::VERSION,
+            transfer_id: [0xab; 32],
+            sender: vec![0x11; 20],
+            recipient: vec![0x22; 32],
+            destination_token: [0x55; 32],
+            amount_usd: 1_234_567_890,
+            min_destination_amount: 1_200_000_000,
+            nonce: 42,
+            source_chain: 2,
+            timestamp: 1_700_000_000,
+        }
+    }
+
+    #[test]
+    fn round_trips() {
+        let message = sample();
+        let bytes = message.encode().unwrap();
+        assert_eq!(bytes.len(), message.encoded_len());
+        assert_eq!(TransferMessage::decode(&bytes).unwrap(), message);
+
+        let swapped = TransferMessage {
+            sender: vec![0x33; 32],
+            recipient: vec![0x44; 20],
+            timestamp: -1,
+            ..sample()
+        };
+        let bytes = swapped.encode().unwrap();
+        assert_eq!(TransferMessage::decode(&bytes).unwrap(), swapped);
+    }
+
+    #[test]
+    fn encodes_big_endian_layout() {
+        let bytes = sample().encode().unwrap();
+        assert_eq!(bytes[0], 2);
+        assert_eq!(&bytes[1..33], &[0xab; 32]);
+        assert_eq!(bytes[33], 20);
+        assert_eq!(bytes[54], 32);
+        assert_eq!(&bytes[87..119], &[0x55; 32]);
+        let tail = &bytes[119..];
+        assert_eq!(&tail[0..8], &1_234_567_890u64.to_be_bytes());
+        assert_eq!(&tail[8..16], &1_200_000_000u64.to_be_bytes());
+        assert_eq!(&tail[16..24], &42u64.to_be_bytes());
+        assert_eq!(&tail[24..26], &[0x00, 0x02]);
+        assert_eq!(&tail[26..34], &1_700_000_000i64.to_be_bytes());
+        assert_eq!(
+            TransferMessage::peek_transfer_id(&bytes),
+            sample().transfer_id
+        );
+    }
+
+    #[test]
+    fn offsets_match_doc_table() {
+        for (s, r) in [(20, 20), (20, 32), (32, 20), (32, 32)] {
+            let message = TransferMessage {
+                sender: vec![0x11; s],
+                recipient: vec![0x22; r],
+                ..sample()
+            };
+            let bytes = message.encode().unwrap();
+            assert_eq!(bytes.len(), 101 + s + r);
+            assert_eq!(bytes[33] as usize, s);
+            assert_eq!(bytes[34 + s] as usize, r);
+            let at = |offset: usize| &bytes[offset + s + r..];
+            assert_eq!(&at(35)[..32], &message.destination_token);
+            assert_eq!(&at(67)[..8], &message.amount_usd.to_be_bytes());
+            assert_eq!(&at(75)[..8], &message.min_destination_amount.to_be_bytes());
+            assert_eq!(&at(83)[..8], &message.nonce.to_be_bytes());
+            assert_eq!(&at(91)[..2], &message.source_chain.to_be_bytes());
+            assert_eq!(at(93), &message.timestamp.to_be_bytes());
+        }
+    }
+
+    #[test]
+    fn rejects_trailing_bytes() {
+        let mut bytes = sample().encode().unwrap();
+        bytes.push(0);
+        assert_eq!(
+            TransferMessage::decode(&bytes).unwrap_err(),
+            BridgeError::TrailingMessageBytes.into()
+        );
+    }
+
+    #[test]
+    fn rejects_truncated_input() {
+        let bytes = sample().encode().unwrap();
+        for len in 0..bytes.len() {
+            assert!(TransferMessage::decode(&bytes[..len]).is_err());
+        }
+        assert_eq!(
+            TransferMessage::decode(&bytes[..bytes.len() - 1]).unwrap_err(),
+            BridgeError::TruncatedMessage.into()
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_version() {
+        let mut bytes = sample().encode().unwrap();
+        for version in [0, 1, TransferMessage::VERSION + 1] {
+            bytes[0] = version;
+            assert_eq!(
+                TransferMessage::decode(&bytes).unwrap_err(),
+                BridgeError::UnsupportedMessageVersion.into()
+            );
+        }
+
+        let message = TransferMessage {
+            version: 1,
+            ..sample()
+        };
+        assert_eq!(
+            message.encode().unwrap_err(),
+            BridgeError::UnsupportedMessageVersion.into()
+        );
+    }
+
+    #[test]
+    fn rejects_bad_address_length() {
+        let mut bytes = sample().encode().unwrap();
+        bytes[33] = 21;
+        assert_eq!(
+            TransferMessage::decode(&bytes).unwrap_err(),
+            BridgeError::InvalidAddressLength.into()
+        );
+
+        let message = TransferMessage {
+            recipient: vec![0; 31],
+            ..sample()
+        };
+        assert_eq!(
+            message.encode().unwrap_err(),
+            BridgeError::InvalidAddressLength.into()
+        );
+    }
+}
@@ -0,0 +1,190 @@
+//! Role-based access control.
+//!
+//! Every grant is a [`RoleMember`] PDA at `["role", role, member]`; holding a
+//! role means that account exists. `BridgeConfig::admin` is the owner key: it
+//! implicitly holds every role and is the only key that can grant or revoke
+//! [`Role::Admin`]. Admins manage the other roles.
+//!
+//! Instructions gated by a role take the signer as `authority` and its
+//! membership as an optional `role_member` account, and check them with
+//! [`has_role`]:
+//!
+//! ```ignore
+//! #[account(
+//!     seeds = [BridgeConfig::SEED],
+//!     bump = config.bump,
+//!     constraint = has_role(&config, &authority.key(), role_member.as_deref(), Role::Operator)
+//!         @ BridgeError::Unauthorized
+//! )]
+//! pub config: Account<'info, BridgeConfig>,
+//!
+//! #[account(
+//!     seeds = [RoleMember::SEED, &[role_member.role as u8], authority.key().as_ref()],
+//!     bump = role_member.bump
+//! )]
+//! pub role_member: Option<Account<'info, RoleMember>>,
+//! ```
+//!
+//! The owner can leave `role_member` out.
+
+use anchor_lang::prelude::*;
+
+use crate::state::BridgeConfig;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum Role {
+    /// Manages the other roles, guardian sets and the circuit breaker, and
+    /// may lift pauses.
+    Admin,
+    /// May pause transfers but not unpause them.
+    Pauser,
+    /// Sets the bridge fee.
+    FeeManager,
+    /// Registers and updates foreign emitters.
+    EmitterManager,
+    /// Manages supported tokens, vaults and rate limits.
+    Operator,
+    /// May freeze delayed releases during their window.
+    Watcher,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct RoleMember {
+    pub role: Role,
+    pub member: Pubkey,
+    pub granted_by: Pubkey,
+    pub granted_at: i64,
+    pub bump: u8,
+}
+
+impl RoleMember {
+    pub const SEED: &'static [u8] = b"role";
+}
+
+/// True if `authority` is the owner, or `role_member` proves it holds `role`.
+///
+/// `role_member` must already be constrained to its PDA; the member check
+/// here is a second line of defence.
+pub fn has_role(
+    config: &BridgeConfig,
+    authority: &Pubkey,
+    role_member: Option<&RoleMember>,
+    role: Role,
+) -> bool {
+    *authority == config.admin
+        || role_member.is_some_and(|m| m.role == role && m.member == *authority)
+}
+
+/// Like [`has_role`], but holders of `Admin` also pass.
+pub fn has_role_or_admin(
+    config: &BridgeConfig,
+    authority: &Pubkey,
+    role_member: Option<&RoleMember>,
+    role: Role,
+) -> bool {
+    has_role(config, authority, role_member, role)
+        || has_role(config, authority, role_member, Role::Admin)
+}
+
+/// Only the owner grants or revokes `Admin`; admins handle the rest.
+pub fn can_manage_role(
+    config: &BridgeConfig,
+    authority: &Pubkey,
+    role_member: Option<&RoleMember>,
+    role: Role,
+) -> bool {
+    match role {
+        Role::Admin => *authority == config.admin,
+        _ => has_role(config, authority, role_member, Role::Admin),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(role: Role, member: Pubkey) -> RoleMember {
+        RoleMember {
+            role,
+            member,
+            granted_by: Pubkey::default(),
+            granted_at: 0,
+            bump: 0,
+        }
+    }
+
+    fn config() -> BridgeConfig {
+        BridgeConfig {
+            admin: Pubkey::new_unique(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn owner_holds_every_role() {
+        let config = config();
+        for role in [Role::Admin, Role::Pauser, Role::Operator] {
+            assert!(has_role(&config, &config.admin, None, role));
+            assert!(can_manage_role(&config, &config.admin, None, role));
+        }
+    }
+
+    #[test]
+    fn membership_is_per_role_and_member() {
+        let config = config();
+        let key = Pubkey::new_unique();
+        let operator = member(Role::Operator, key);
+
+        assert!(has_role(&config, &key, Some(&operator), Role::Operator));
+        assert!(!has_role(&config, &key, Some(&operator), Role::Pauser));
+        assert!(!has_role(&config, &key, None, Role::Operator));
+        assert!(!has_role(
+            &config,
+            &Pubkey::new_unique(),
+            Some(&operator),
+            Role::Operator
+        ));
+    }
+
+    #[test]
+    fn only_owner_manages_admins() {
+        let config = config();
+        let key = Pubkey::new_unique();
+        let admin = member(Role::Admin, key);
+
+        assert!(can_manage_role(&config, &key, Some(&admin), Role::Pauser));
+        assert!(can_manage_role(
+            &config,
+            &key,
+            Some(&admin),
+            Role::FeeManager
+        ));
+        assert!(!can_manage_role(&config, &key, Some(&admin), Role::Admin));
+
+        let operator = member(Role::Operator, key);
+        assert!(!can_manage_role(
+            &config,
+            &key,
+            Some(&operator),
+            Role::Operator
+        ));
+    }
+
+    #[test]
+    fn admins_pass_role_or_admin_checks() {
+        let config = config();
+        let key = Pubkey::new_unique();
+        let admin = member(Role::Admin, key);
+        let pauser = member(Role::Pauser, key);
+
+        assert!(has_role_or_admin(&config, &key, Some(&admin), Role::Pauser));
+        assert!(has_role_or_admin(&config, &key, Some(&pauser), Role::Pauser));
+        assert!(!has_role_or_admin(
+            &config,
+            &key,
+            Some(&pauser),
+            Role::FeeManager
+        ));
+    }
+}
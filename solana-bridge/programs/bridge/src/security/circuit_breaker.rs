@@ -0,0 +1,348 @@
+//! Outflow circuit breaker.
+//!
+//! Every release from a vault is recorded, in USD, in an hourly ring buffer
+//! kept per mint. After each release the breaker compares the buffer against
+//! the thresholds in [`CircuitBreaker`]; if either is crossed it trips and
+//! `withdraw` stays closed until the admin resets it.
+//!
+//! `assert_solvency` can also trip the breaker when a vault falls short of
+//! its ledger.
+//!
+//! Withdrawals held for the release delay are recorded when they are
+//! finalized, not when they are queued.
+//!
+//! The release that crosses a threshold still completes: the breaker stops
+//! the *next* withdrawal, and per-transfer limits bound what a single one can
+//! move.
+
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::CircuitBreakerTripped;
+
+/// Length of one outflow bucket.
+pub const OUTFLOW_BUCKET_SECONDS: i64 = 3_600;
+
+/// Buckets kept per mint; the rolling window is 24 hours.
+pub const OUTFLOW_BUCKETS: usize = 24;
+
+/// Spike multiplier applied when the bridge is initialized.
+pub const DEFAULT_SPIKE_MULTIPLIER: u16 = 10;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum TripReason {
+    /// Outflow over the rolling window exceeded `window_outflow_cap`.
+    WindowCapExceeded,
+    /// Outflow in the current hour exceeded `spike_multiplier` times the
+    /// trailing hourly average.
+    OutflowSpike,
+    /// A vault held less than its ledger says it must.
+    LedgerMismatch,
+}
+
+/// Breaker thresholds and trip state, stored on [`crate::state::BridgeConfig`].
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq, InitSpace)]
+pub struct CircuitBreaker {
+    /// Maximum USD outflow per mint over the rolling window; zero disables.
+    pub window_outflow_cap: u64,
+    /// Current-hour outflow allowed relative to the trailing hourly average;
+    /// zero disables spike detection.
+    pub spike_multiplier: u16,
+    /// Current-hour USD outflow up to which spikes are ignored, so a quiet
+    /// history does not trip on ordinary traffic.
+    pub spike_floor: u64,
+    /// Why the breaker tripped; `None` while closed.
+    pub trip_reason: Option<TripReason>,
+    pub tripped_at: i64,
+    /// Mint whose outflow tripped the breaker.
+    pub tripped_mint: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CircuitBreakerParams {
+    pub window_outflow_cap: u64,
+    pub spike_multiplier: u16,
+    pub spike_floor: u64,
+}
+
+impl CircuitBreakerParams {
+    pub fn validate(&self, max_transfer: u64) -> Result<()> {
+        require!(
+            self.window_outflow_cap == 0 || self.window_outflow_cap >= max_transfer,
+            BridgeError::InvalidCircuitBreakerConfig
+        );
+        require!(
+            self.spike_multiplier == 0 || self.spike_multiplier >= 2,
+            BridgeError::InvalidCircuitBreakerConfig
+        );
+        Ok(())
+    }
+}
+
+impl CircuitBreaker {
+    /// Thresholds used until the admin tunes them: trip on a day's volume
+    /// cap, or on a 10x hourly spike larger than a single maximum transfer.
+    pub fn initial(daily_volume_cap: u64, max_transfer: u64) -> Self {
+        Self {
+            window_outflow_cap: daily_volume_cap,
+            spike_multiplier: DEFAULT_SPIKE_MULTIPLIER,
+            spike_floor: max_transfer,
+            ..Default::default()
+        }
+    }
+
+    pub fn is_tripped(&self) -> bool {
+        self.trip_reason.is_some()
+    }
+
+    pub fn check_closed(&self) -> Result<()> {
+        require!(!self.is_tripped(), BridgeError::CircuitBreakerTripped);
+        Ok(())
+    }
+
+    pub fn configure(&mut self, params: &CircuitBreakerParams) {
+        self.window_outflow_cap = params.window_outflow_cap;
+        self.spike_multiplier = params.spike_multiplier;
+        self.spike_floor = params.spike_floor;
+    }
+
+    /// Returns the threshold `window` has crossed, if any.
+    pub fn evaluate(&self, window: &OutflowWindow) -> Option<TripReason> {
+        if self.window_outflow_cap > 0 && window.total() > self.window_outflow_cap as u128 {
+            return Some(TripReason::WindowCapExceeded);
+        }
+
+        let current = window.current();
+        if self.spike_multiplier > 0 && current > self.spike_floor {
+            let limit = window.trailing_average() * self.spike_multiplier as u128;
+            if current as u128 > limit {
+                return Some(TripReason::OutflowSpike);
+            }
+        }
+        None
+    }
+
+    pub fn trip(&mut self, reason: TripReason, mint: Pubkey, now: i64) {
+        self.trip_reason = Some(reason);
+        self.tripped_at = now;
+        self.tripped_mint = mint;
+    }
+
+    /// Records a release in `window` and trips the breaker if that crosses
+    /// a threshold.
+    pub fn record_outflow(
+        &mut self,
+        window: &mut OutflowWindow,
+        amount_usd: u64,
+        now: i64,
+    ) -> Result<()> {
+        window.record(amount_usd, now)?;
+        if let Some(reason) = self.evaluate(window) {
+            self.trip(reason, window.mint, now);
+            emit!(CircuitBreakerTripped {
+                reason,
+                mint: window.mint,
+                window_outflow: u64::try_from(window.total()).unwrap_or(u64::MAX),
+                current_outflow: window.current(),
+                tripped_at: now,
+            });
+            msg!("Circuit breaker tripped: {:?}", reason);
+        }
+        Ok(())
+    }
+
+    pub fn reset(&mut self) {
+        self.trip_reason = None;
+        self.tripped_at = 0;
+        self.tripped_mint = Pubkey::default();
+    }
+}
+
+/// Rolling 24-hour record of USD released for one mint.
+#[account]
+#[derive(InitSpace)]
+pub struct OutflowWindow {
+    pub mint: Pubkey,
+    /// USD outflow per hour, indexed by `hour % OUTFLOW_BUCKETS`.
+    pub buckets: [u64; 24],
+    /// Hour (unix time / `OUTFLOW_BUCKET_SECONDS`) of the newest bucket.
+    pub current_hour: i64,
+    pub bump: u8,
+}
+
+impl OutflowWindow {
+    pub const SEED: &'static [u8] = b"outflow";
+
+    /// Adds `amount_usd` to the bucket for `now`, first clearing any buckets
+    /// that have fallen out of the window.
+    pub fn record(&mut self, amount_usd: u64, now: i64) -> Result<()> {
+        self.advance(now.div_euclid(OUTFLOW_BUCKET_SECONDS));
+        let slot = self.slot(self.current_hour);
+        self.buckets[slot] = self.buckets[slot]
+            .checked_add(amount_usd)
+            .ok_or(BridgeError::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn clear(&mut self) {
+        self.buckets = [0; OUTFLOW_BUCKETS];
+    }
+
+    /// Outflow over the whole window.
+    pub fn total(&self) -> u128 {
+        self.buckets.iter().map(|&b| b as u128).sum()
+    }
+
+    /// Outflow in the newest bucket.
+    pub fn current(&self) -> u64 {
+        self.buckets[self.slot(self.current_hour)]
+    }
+
+    /// Mean hourly outflow over the window, excluding the newest bucket.
+    pub fn trailing_average(&self) -> u128 {
+        (self.total() - self.current() as u128) / (OUTFLOW_BUCKETS as u128 - 1)
+    }
+
+    fn advance(&mut self, hour: i64) {
+        // A clock that moves backwards keeps writing to the newest bucket.
+        if hour <= self.current_hour {
+            return;
+        }
+        let elapsed = (hour - self.current_hour).min(OUTFLOW_BUCKETS as i64);
+        for step in 0..elapsed {
+            let slot = self.slot(hour - step);
+            self.buckets[slot] = 0;
+        }
+        self.current_hour = hour;
+    }
+
+    fn slot(&self, hour: i64) -> usize {
+        hour.rem_euclid(OUTFLOW_BUCKETS as i64) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: i64 = OUTFLOW_BUCKET_SECONDS;
+    const START: i64 = 1_700_000_000;
+
+    fn window() -> OutflowWindow {
+        OutflowWindow {
+            mint: Pubkey::default(),
+            buckets: [0; OUTFLOW_BUCKETS],
+            current_hour: START / HOUR,
+            bump: 0,
+        }
+    }
+
+    fn breaker() -> CircuitBreaker {
+        CircuitBreaker {
+            window_outflow_cap: 0,
+            spike_multiplier: DEFAULT_SPIKE_MULTIPLIER,
+            spike_floor: 1_000,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn window_rolls_off_old_buckets() {
+        let mut w = window();
+        w.record(100, START).unwrap();
+        w.record(50, START + 10).unwrap();
+        assert_eq!(w.current(), 150);
+
+        w.record(10, START + HOUR).unwrap();
+        assert_eq!(w.current(), 10);
+        assert_eq!(w.total(), 160);
+
+        // The first hour drops out once a full window has passed.
+        w.record(1, START + 24 * HOUR).unwrap();
+        assert_eq!(w.total(), 11);
+
+        w.record(1, START + 100 * HOUR).unwrap();
+        assert_eq!(w.total(), 1);
+    }
+
+    #[test]
+    fn clock_going_backwards_uses_newest_bucket() {
+        let mut w = window();
+        w.record(5, START + HOUR).unwrap();
+        w.record(7, START).unwrap();
+        assert_eq!(w.current(), 12);
+        assert_eq!(w.total(), 12);
+    }
+
+    #[test]
+    fn trips_on_window_cap() {
+        let cb = CircuitBreaker {
+            window_outflow_cap: 1_000,
+            spike_multiplier: 0,
+            ..breaker()
+        };
+        let mut w = window();
+        w.record(600, START).unwrap();
+        assert_eq!(cb.evaluate(&w), None);
+        w.record(400, START + 3 * HOUR).unwrap();
+        assert_eq!(cb.evaluate(&w), None);
+        w.record(1, START + 4 * HOUR).unwrap();
+        assert_eq!(cb.evaluate(&w), Some(TripReason::WindowCapExceeded));
+    }
+
+    #[test]
+    fn trips_on_spike_over_trailing_average() {
+        let cb = breaker();
+        let mut w = window();
+        for hour in 0..23 {
+            w.record(2_300, START + hour * HOUR).unwrap();
+        }
+        // Trailing average is 2_300, so 23_000 in the newest hour is the limit.
+        w.record(23_000, START + 23 * HOUR).unwrap();
+        assert_eq!(cb.evaluate(&w), None);
+        w.record(1, START + 23 * HOUR).unwrap();
+        assert_eq!(cb.evaluate(&w), Some(TripReason::OutflowSpike));
+    }
+
+    #[test]
+    fn spike_below_floor_is_ignored() {
+        let cb = breaker();
+        let mut w = window();
+        w.record(999, START).unwrap();
+        assert_eq!(cb.evaluate(&w), None);
+        w.record(1, START).unwrap();
+        assert_eq!(cb.evaluate(&w), None);
+        w.record(1, START).unwrap();
+        assert_eq!(cb.evaluate(&w), Some(TripReason::OutflowSpike));
+    }
+
+    #[test]
+    fn single_max_transfer_after_quiet_day_does_not_trip() {
+        let max_transfer = 1_000_000_000_000;
+        let cb = CircuitBreaker::initial(10 * max_transfer, max_transfer);
+        let mut w = window();
+        w.record(max_transfer, START).unwrap();
+        assert_eq!(cb.evaluate(&w), None);
+
+        w.record(1, START).unwrap();
+        assert_eq!(cb.evaluate(&w), Some(TripReason::OutflowSpike));
+    }
+
+    #[test]
+    fn trip_and_reset() {
+        let mut cb = breaker();
+        assert!(cb.check_closed().is_ok());
+
+        cb.trip(TripReason::OutflowSpike, Pubkey::new_unique(), START);
+        assert!(cb.is_tripped());
+        assert_eq!(
+            cb.check_closed().unwrap_err(),
+            BridgeError::CircuitBreakerTripped.into()
+        );
+
+        cb.reset();
+        assert!(!cb.is_tripped());
+        assert_eq!(cb.tripped_mint, Pubkey::default());
+        assert_eq!(cb.spike_multiplier, DEFAULT_SPIKE_MULTIPLIER);
+    }
+}
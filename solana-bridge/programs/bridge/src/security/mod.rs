@@ -0,0 +1,13 @@
+pub mod access_control;
+pub mod circuit_breaker;
+pub mod pause;
+pub mod price_guard;
+pub mod rate_limiter;
+pub mod timelock;
+
+pub use access_control::*;
+pub use circuit_breaker::*;
+pub use pause::*;
+pub use price_guard::*;
+pub use rate_limiter::*;
+pub use timelock::*;
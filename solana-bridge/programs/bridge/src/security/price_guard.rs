@@ -0,0 +1,155 @@
+//! Depeg protection for stablecoins.
+//!
+//! A token whose [`PriceGuard`] names a price feed only moves while that
+//! feed is trading, fresh, and inside the configured band. Transfers are
+//! valued at a fixed 1:1 USD rate, so bridging a depegged stablecoin would
+//! let a caller swap it for a healthy one on the other side.
+
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::integrations::pyth::PriceFeed;
+
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace,
+)]
+pub struct PriceGuard {
+    /// Pyth price account of the token; the default key disables the guard.
+    pub feed: Pubkey,
+    /// Lowest accepted price, in USD (6 decimals).
+    pub min_price_usd: u64,
+    /// Highest accepted price, in USD (6 decimals).
+    pub max_price_usd: u64,
+    /// Oldest price accepted, in seconds.
+    pub max_price_age: i64,
+}
+
+impl PriceGuard {
+    pub fn is_enabled(&self) -> bool {
+        self.feed != Pubkey::default()
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if self.is_enabled() {
+            require!(
+                self.min_price_usd > 0
+                    && self.min_price_usd <= self.max_price_usd
+                    && self.max_price_age > 0,
+                BridgeError::InvalidPriceGuard
+            );
+        }
+        Ok(())
+    }
+
+    /// Checks the token's price if the guard is enabled. `feed` must then be
+    /// the configured price account.
+    pub fn check(&self, feed: Option<&AccountInfo>, now: i64) -> Result<()> {
+        if !self.is_enabled() {
+            return Ok(());
+        }
+        let account = feed.ok_or(BridgeError::InvalidPriceFeed)?;
+        require_keys_eq!(*account.key, self.feed, BridgeError::InvalidPriceFeed);
+        self.check_price(&PriceFeed::load(account)?, now)
+    }
+
+    pub fn check_price(&self, feed: &PriceFeed, now: i64) -> Result<()> {
+        let age = now.saturating_sub(feed.publish_time);
+        if !feed.trading || age > self.max_price_age {
+            msg!("Price is {} seconds old, trading: {}", age, feed.trading);
+            return err!(BridgeError::StalePrice);
+        }
+        let price = feed.price_usd()?;
+        if !(self.min_price_usd..=self.max_price_usd).contains(&price) {
+            msg!(
+                "Price {} outside {} to {}",
+                price,
+                self.min_price_usd,
+                self.max_price_usd
+            );
+            return err!(BridgeError::PriceOutOfBand);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integrations::pyth::mock_price_account;
+
+    const NOW: i64 = 1_700_000_000;
+
+    fn guard() -> PriceGuard {
+        PriceGuard {
+            feed: Pubkey::new_unique(),
+            min_price_usd: 980_000,
+            max_price_usd: 1_020_000,
+            max_price_age: 60,
+        }
+    }
+
+    fn feed(price: i64, publish_time: i64, trading: bool) -> PriceFeed {
+        PriceFeed::parse(&mock_price_account(price, -8, publish_time, trading)).unwrap()
+    }
+
+    #[test]
+    fn accepts_price_inside_band() {
+        let guard = guard();
+        assert!(guard
+            .check_price(&feed(100_000_000, NOW, true), NOW)
+            .is_ok());
+        assert!(guard.check_price(&feed(98_000_000, NOW, true), NOW).is_ok());
+        assert!(guard
+            .check_price(&feed(102_000_000, NOW - 60, true), NOW)
+            .is_ok());
+    }
+
+    #[test]
+    fn rejects_depeg() {
+        let guard = guard();
+        assert_eq!(
+            guard
+                .check_price(&feed(97_999_999, NOW, true), NOW)
+                .unwrap_err(),
+            BridgeError::PriceOutOfBand.into()
+        );
+        assert!(guard
+            .check_price(&feed(102_000_100, NOW, true), NOW)
+            .is_err());
+    }
+
+    #[test]
+    fn rejects_stale_or_halted_price() {
+        let guard = guard();
+        assert_eq!(
+            guard
+                .check_price(&feed(100_000_000, NOW - 61, true), NOW)
+                .unwrap_err(),
+            BridgeError::StalePrice.into()
+        );
+        assert_eq!(
+            guard
+                .check_price(&feed(100_000_000, NOW, false), NOW)
+                .unwrap_err(),
+            BridgeError::StalePrice.into()
+        );
+    }
+
+    #[test]
+    fn disabled_guard_needs_no_feed() {
+        let disabled = PriceGuard::default();
+        assert!(disabled.validate().is_ok());
+        assert!(disabled.check(None, NOW).is_ok());
+
+        assert_eq!(
+            guard().check(None, NOW).unwrap_err(),
+            BridgeError::InvalidPriceFeed.into()
+        );
+        let inverted = PriceGuard {
+            min_price_usd: 1_020_000,
+            max_price_usd: 980_000,
+            ..guard()
+        };
+        assert!(inverted.validate().is_err());
+    }
+}
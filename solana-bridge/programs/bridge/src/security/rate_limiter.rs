@@ -0,0 +1,241 @@
+//! Token-bucket rate limiting of bridged volume.
+//!
+//! Each (chain, mint, direction) has its own [`RateLimit`] bucket, and the
+//! config holds one more bucket shared by every transfer that enforces
+//! `daily_volume_cap`. Amounts are in USD (6 decimals). A transfer must fit
+//! in every bucket it touches; when it does not, the log says how many
+//! seconds to wait before retrying.
+
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::state::TransferDirection;
+
+pub const SECONDS_PER_DAY: u64 = 86_400;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq, InitSpace)]
+pub struct TokenBucket {
+    /// Most the bucket can hold, i.e. the largest burst allowed.
+    pub capacity: u64,
+    /// Amount restored every `refill_period` seconds, spread evenly.
+    pub refill_amount: u64,
+    pub refill_period: u64,
+    /// Amount available as of `last_refill`.
+    pub available: u64,
+    pub last_refill: i64,
+    /// Refill earned by `last_refill` but not yet a whole unit, in units of
+    /// `1 / refill_period`. Carrying it keeps frequent calls from losing the
+    /// fraction each time.
+    pub refill_remainder: u64,
+}
+
+impl TokenBucket {
+    /// A full bucket.
+    pub fn new(capacity: u64, refill_per_second: u64, now: i64) -> Self {
+        Self {
+            capacity,
+            refill_amount: refill_per_second,
+            refill_period: 1,
+            available: capacity,
+            last_refill: now,
+            refill_remainder: 0,
+        }
+    }
+
+    /// A bucket that refills its whole capacity once a day.
+    pub fn daily(capacity: u64, now: i64) -> Self {
+        Self {
+            refill_amount: capacity,
+            refill_period: SECONDS_PER_DAY,
+            ..Self::new(capacity, 0, now)
+        }
+    }
+
+    /// Amount available at `now`, and the remainder to carry from there.
+    fn refill(&self, now: i64) -> (u64, u64) {
+        let elapsed = now.saturating_sub(self.last_refill).max(0) as u128;
+        let period = self.refill_period.max(1) as u128;
+        let earned = elapsed * self.refill_amount as u128 + self.refill_remainder as u128;
+        let available = self.available as u128 + earned / period;
+        if available >= self.capacity as u128 {
+            (self.capacity, 0)
+        } else {
+            (available as u64, (earned % period) as u64)
+        }
+    }
+
+    pub fn available_at(&self, now: i64) -> u64 {
+        self.refill(now).0
+    }
+
+    /// Seconds until `amount` fits, or `None` if it never will.
+    pub fn wait_seconds(&self, amount: u64, now: i64) -> Option<u64> {
+        let (available, remainder) = self.refill(now);
+        let missing = amount.saturating_sub(available);
+        if missing == 0 {
+            return Some(0);
+        }
+        if amount > self.capacity || self.refill_amount == 0 {
+            return None;
+        }
+        let needed = missing as u128 * self.refill_period.max(1) as u128 - remainder as u128;
+        u64::try_from(needed.div_ceil(self.refill_amount as u128)).ok()
+    }
+
+    pub fn consume(&mut self, amount: u64, now: i64) -> Result<()> {
+        let (available, remainder) = self.refill(now);
+        if amount > available {
+            match self.wait_seconds(amount, now) {
+                Some(wait) => msg!(
+                    "Rate limit exceeded: {} available, retry in {} seconds",
+                    available,
+                    wait
+                ),
+                None => msg!(
+                    "Rate limit exceeded: {} exceeds capacity {}",
+                    amount,
+                    self.capacity
+                ),
+            }
+            return err!(BridgeError::RateLimitExceeded);
+        }
+        self.available = available - amount;
+        self.refill_remainder = remainder;
+        self.last_refill = now.max(self.last_refill);
+        Ok(())
+    }
+
+    /// Changes the limits, keeping what is currently available up to the new
+    /// capacity. A partial unit earned at the old rate is dropped.
+    pub fn reconfigure(&mut self, capacity: u64, refill_amount: u64, refill_period: u64, now: i64) {
+        self.available = self.available_at(now).min(capacity);
+        self.last_refill = now.max(self.last_refill);
+        self.refill_remainder = 0;
+        self.capacity = capacity;
+        self.refill_amount = refill_amount;
+        self.refill_period = refill_period;
+    }
+}
+
+/// Rate limit of one transfer direction for one mint and foreign chain.
+#[account]
+#[derive(InitSpace)]
+pub struct RateLimit {
+    /// Wormhole chain id on the other side of the transfer.
+    pub chain: u16,
+    pub mint: Pubkey,
+    pub direction: TransferDirection,
+    pub bucket: TokenBucket,
+    pub bump: u8,
+}
+
+impl RateLimit {
+    pub const SEED: &'static [u8] = b"rate_limit";
+
+    pub fn check_params(capacity: u64, refill_per_second: u64) -> Result<()> {
+        require!(
+            capacity > 0 && refill_per_second > 0 && refill_per_second <= capacity,
+            BridgeError::InvalidRateLimit
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_700_000_000;
+
+    #[test]
+    fn consumes_and_refills() {
+        let mut bucket = TokenBucket::new(1_000, 10, NOW);
+        bucket.consume(600, NOW).unwrap();
+        assert_eq!(bucket.available_at(NOW), 400);
+        assert_eq!(bucket.available_at(NOW + 30), 700);
+        assert_eq!(bucket.available_at(NOW + 1_000), 1_000);
+
+        bucket.consume(700, NOW + 30).unwrap();
+        assert_eq!(bucket.available_at(NOW + 30), 0);
+    }
+
+    #[test]
+    fn reports_wait_time() {
+        let mut bucket = TokenBucket::new(1_000, 10, NOW);
+        bucket.consume(1_000, NOW).unwrap();
+
+        assert_eq!(bucket.wait_seconds(0, NOW), Some(0));
+        assert_eq!(bucket.wait_seconds(95, NOW), Some(10));
+        assert_eq!(bucket.wait_seconds(95, NOW + 5), Some(5));
+        assert_eq!(bucket.wait_seconds(1_001, NOW), None);
+
+        assert_eq!(
+            bucket.consume(95, NOW + 9).unwrap_err(),
+            BridgeError::RateLimitExceeded.into()
+        );
+        // A failed attempt leaves the bucket untouched.
+        bucket.consume(95, NOW + 10).unwrap();
+        assert_eq!(bucket.available_at(NOW + 10), 5);
+    }
+
+    #[test]
+    fn daily_bucket_enforces_cap() {
+        let cap = 500_000_000_000;
+        let mut bucket = TokenBucket::daily(cap, NOW);
+        bucket.consume(cap, NOW).unwrap();
+        // About $347 trickles back per minute.
+        assert!(bucket.consume(400_000_000, NOW + 60).is_err());
+        bucket.consume(300_000_000, NOW + 60).unwrap();
+        // A day after draining it, all but what was spent since is back.
+        let day = SECONDS_PER_DAY as i64;
+        assert_eq!(bucket.available_at(NOW + day), cap - 300_000_000);
+        assert_eq!(bucket.available_at(NOW + day + 60), cap);
+    }
+
+    #[test]
+    fn frequent_calls_do_not_lose_refill() {
+        // Neither cap divides evenly into seconds.
+        let cap = 1_000_000_007;
+        let mut often = TokenBucket::daily(cap, NOW);
+        let mut once = TokenBucket::daily(cap, NOW);
+        often.consume(cap, NOW).unwrap();
+        once.consume(cap, NOW).unwrap();
+
+        for t in 1..=3_600 {
+            often.consume(1, NOW + t).unwrap();
+        }
+        once.consume(3_600, NOW + 3_600).unwrap();
+        assert_eq!(often, once);
+        assert_eq!(
+            often.available_at(NOW + 3_600),
+            cap * 3_600 / 86_400 - 3_600
+        );
+
+        // Waiting exactly as long as reported is always enough.
+        let amount = 100_000_000;
+        let wait = often.wait_seconds(amount, NOW + 3_600).unwrap() as i64;
+        assert!(wait > 0);
+        assert!(often.available_at(NOW + 3_600 + wait) >= amount);
+        assert!(often.available_at(NOW + 3_599 + wait) < amount);
+    }
+
+    #[test]
+    fn reconfigure_clamps_to_new_capacity() {
+        let mut bucket = TokenBucket::new(1_000, 10, NOW);
+        bucket.reconfigure(500, 1, 1, NOW + 5);
+        assert_eq!(bucket.available_at(NOW + 5), 500);
+
+        bucket.consume(500, NOW + 5).unwrap();
+        bucket.reconfigure(2_000, 100, 1, NOW + 6);
+        assert_eq!(bucket.available_at(NOW + 6), 1);
+        assert_eq!(bucket.available_at(NOW + 7), 101);
+    }
+
+    #[test]
+    fn rejects_invalid_params() {
+        assert!(RateLimit::check_params(1_000, 10).is_ok());
+        assert!(RateLimit::check_params(0, 0).is_err());
+        assert!(RateLimit::check_params(1_000, 0).is_err());
+        assert!(RateLimit::check_params(10, 11).is_err());
+    }
+}
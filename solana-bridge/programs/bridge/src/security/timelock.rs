@@ -0,0 +1,163 @@
+//! Timelock for sensitive configuration changes.
+//!
+//! A change is first scheduled as a [`TimelockOperation`] PDA keyed by the
+//! hash of its [`TimelockAction`], which makes the pending change public for
+//! at least `BridgeConfig::timelock_delay` seconds. During that time it can be
+//! cancelled. Afterwards the instruction that applies the change derives the
+//! same PDA from its own arguments, so it can only run exactly what was
+//! scheduled, and closes it.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+
+use crate::errors::BridgeError;
+use crate::events::OperationExecuted;
+use crate::security::Role;
+use crate::state::MAX_GUARDIANS;
+
+/// Shortest delay the config accepts.
+pub const MIN_TIMELOCK_DELAY: i64 = 3_600;
+
+/// Longest delay the config accepts.
+pub const MAX_TIMELOCK_DELAY: i64 = 30 * 86_400;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub enum TimelockAction {
+    SetFee {
+        fee_bps: u16,
+    },
+    SetTransferLimits {
+        min_transfer: u64,
+        max_transfer: u64,
+        daily_volume_cap: u64,
+    },
+    RegisterEmitter {
+        chain: u16,
+        address: [u8; 32],
+    },
+    UpdateEmitter {
+        chain: u16,
+        address: [u8; 32],
+    },
+    SetGuardianSet {
+        index: u32,
+        #[max_len(MAX_GUARDIANS)]
+        keys: Vec<[u8; 20]>,
+    },
+    SetReleaseDelay {
+        threshold: u64,
+        delay: i64,
+    },
+}
+
+impl TimelockAction {
+    /// Hash identifying the operation; also its PDA seed.
+    pub fn id(&self) -> [u8; 32] {
+        // Serializing plain data into a Vec cannot fail.
+        keccak::hash(&self.try_to_vec().unwrap()).to_bytes()
+    }
+
+    /// Role that may schedule the action and apply it once it is ready.
+    pub fn role(&self) -> Role {
+        match self {
+            TimelockAction::SetFee { .. } => Role::FeeManager,
+            TimelockAction::RegisterEmitter { .. } | TimelockAction::UpdateEmitter { .. } => {
+                Role::EmitterManager
+            }
+            TimelockAction::SetTransferLimits { .. }
+            | TimelockAction::SetGuardianSet { .. }
+            | TimelockAction::SetReleaseDelay { .. } => Role::Admin,
+        }
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct TimelockOperation {
+    pub id: [u8; 32],
+    pub action: TimelockAction,
+    pub proposer: Pubkey,
+    pub scheduled_at: i64,
+    /// Earliest time the action may be applied.
+    pub eta: i64,
+    pub bump: u8,
+}
+
+impl TimelockOperation {
+    pub const SEED: &'static [u8] = b"timelock";
+
+    pub fn check_delay(delay: i64) -> Result<()> {
+        require!(
+            (MIN_TIMELOCK_DELAY..=MAX_TIMELOCK_DELAY).contains(&delay),
+            BridgeError::InvalidTimelockDelay
+        );
+        Ok(())
+    }
+
+    pub fn check_ready(&self, now: i64) -> Result<()> {
+        if now < self.eta {
+            msg!("Operation ready in {} seconds", self.eta - now);
+            return err!(BridgeError::TimelockNotReady);
+        }
+        Ok(())
+    }
+
+    /// Checks the delay has passed and records the execution. The caller
+    /// closes the account.
+    pub fn execute(&self, executed_by: Pubkey, now: i64) -> Result<()> {
+        self.check_ready(now)?;
+        emit!(OperationExecuted {
+            id: self.id,
+            executed_by,
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_bind_every_argument() {
+        let fee = TimelockAction::SetFee { fee_bps: 30 };
+        assert_eq!(fee.id(), TimelockAction::SetFee { fee_bps: 30 }.id());
+        assert_ne!(fee.id(), TimelockAction::SetFee { fee_bps: 31 }.id());
+
+        let register = TimelockAction::RegisterEmitter {
+            chain: 2,
+            address: [1; 32],
+        };
+        let update = TimelockAction::UpdateEmitter {
+            chain: 2,
+            address: [1; 32],
+        };
+        assert_ne!(register.id(), update.id());
+    }
+
+    #[test]
+    fn readiness_follows_eta() {
+        let operation = TimelockOperation {
+            id: [0; 32],
+            action: TimelockAction::SetFee { fee_bps: 0 },
+            proposer: Pubkey::default(),
+            scheduled_at: 1_000,
+            eta: 1_000 + MIN_TIMELOCK_DELAY,
+            bump: 0,
+        };
+        assert_eq!(
+            operation.check_ready(1_000).unwrap_err(),
+            BridgeError::TimelockNotReady.into()
+        );
+        assert!(operation.check_ready(operation.eta - 1).is_err());
+        assert!(operation.check_ready(operation.eta).is_ok());
+    }
+
+    #[test]
+    fn delay_is_bounded() {
+        assert!(TimelockOperation::check_delay(MIN_TIMELOCK_DELAY).is_ok());
+        assert!(TimelockOperation::check_delay(MAX_TIMELOCK_DELAY).is_ok());
+        assert!(TimelockOperation::check_delay(MIN_TIMELOCK_DELAY - 1).is_err());
+        assert!(TimelockOperation::check_delay(MAX_TIMELOCK_DELAY + 1).is_err());
+    }
+}
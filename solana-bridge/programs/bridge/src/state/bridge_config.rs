@@ -0,0 +1,200 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::security::{CircuitBreaker, PauseFlags, TokenBucket};
+
+/// 100% expressed in basis points.
+pub const MAX_BPS: u16 = 10_000;
+
+/// Highest bridge fee the config will accept (10%).
+pub const MAX_FEE_BPS: u16 = 1_000;
+
+/// Highest slippage tolerance the config will accept (10%).
+pub const MAX_SLIPPAGE_BPS: u16 = 1_000;
+
+/// Shortest window watchers get to veto a delayed release.
+pub const MIN_RELEASE_DELAY: i64 = 600;
+
+/// Longest a release can be held before anyone may finalize it.
+pub const MAX_RELEASE_DELAY: i64 = 7 * 86_400;
+
+/// Global bridge parameters. There is exactly one of these per deployment.
+#[account]
+#[derive(Default, InitSpace)]
+pub struct BridgeConfig {
+    /// Owner key. Implicitly holds every role and alone manages admins.
+    pub admin: Pubkey,
+    /// Proposed new owner; takes over once it calls `accept_admin`.
+    pub pending_admin: Pubkey,
+    /// Seconds a scheduled sensitive change must wait before it applies.
+    pub timelock_delay: i64,
+    /// Wormhole core bridge program used for messaging.
+    pub wormhole_program: Pubkey,
+    /// Bridge fee charged on deposits, in basis points.
+    pub fee_bps: u16,
+    /// Smallest accepted transfer, in USD (6 decimals).
+    pub min_transfer: u64,
+    /// Largest accepted single transfer, in USD (6 decimals).
+    pub max_transfer: u64,
+    /// Total volume allowed per day, in USD (6 decimals).
+    pub daily_volume_cap: u64,
+    /// Bucket shared by every deposit and withdrawal that enforces
+    /// `daily_volume_cap`.
+    pub daily_volume: TokenBucket,
+    /// Maximum slippage tolerated on AMM legs, in basis points.
+    pub max_slippage_bps: u16,
+    /// Index of the most recently registered guardian set.
+    pub guardian_set_index: u32,
+    /// Counter used to derive unique outbound transfer ids.
+    pub transfer_nonce: u64,
+    /// Outflow thresholds and trip state; a tripped breaker pauses `withdraw`.
+    pub circuit_breaker: CircuitBreaker,
+    /// Withdrawals above this USD value (6 decimals) are held as a pending
+    /// release instead of paid out.
+    pub release_delay_threshold: u64,
+    /// Seconds a held withdrawal waits before it can be finalized.
+    pub release_delay: i64,
+    /// Bridge-wide pause switches.
+    pub paused: PauseFlags,
+    /// Bump of the PDA that owns every vault token account.
+    pub vault_authority_bump: u8,
+    pub bump: u8,
+}
+
+impl BridgeConfig {
+    pub const SEED: &'static [u8] = b"config";
+    pub const VAULT_AUTHORITY_SEED: &'static [u8] = b"vault_authority";
+    pub const VAULT_SEED: &'static [u8] = b"vault";
+
+    /// Signer seeds of the vault authority PDA.
+    pub fn vault_authority_seeds(&self) -> [&[u8]; 2] {
+        [
+            Self::VAULT_AUTHORITY_SEED,
+            std::slice::from_ref(&self.vault_authority_bump),
+        ]
+    }
+
+    /// Checks a set of global transfer limits before they are stored.
+    pub fn validate_transfer_limits(
+        min_transfer: u64,
+        max_transfer: u64,
+        daily_volume_cap: u64,
+    ) -> Result<()> {
+        require!(
+            min_transfer > 0 && min_transfer <= max_transfer,
+            BridgeError::InvalidTransferLimits
+        );
+        require!(
+            daily_volume_cap >= max_transfer,
+            BridgeError::InvalidDailyVolumeCap
+        );
+        Ok(())
+    }
+
+    /// Checks delayed-release settings before they are stored.
+    pub fn validate_release_delay(threshold: u64, delay: i64) -> Result<()> {
+        require!(
+            threshold > 0 && (MIN_RELEASE_DELAY..=MAX_RELEASE_DELAY).contains(&delay),
+            BridgeError::InvalidReleaseDelay
+        );
+        Ok(())
+    }
+
+    /// True if a withdrawal of `amount_usd` must wait out the release delay.
+    pub fn requires_release_delay(&self, amount_usd: u64) -> bool {
+        amount_usd > self.release_delay_threshold
+    }
+
+    /// Rejects amounts outside the configured per-transfer limits.
+    pub fn check_transfer_amount(&self, amount: u64) -> Result<()> {
+        require!(amount >= self.min_transfer, BridgeError::AmountBelowMinimum);
+        require!(amount <= self.max_transfer, BridgeError::AmountAboveMaximum);
+        Ok(())
+    }
+
+    /// Bridge fee owed on `amount`, rounded down.
+    pub fn fee_for(&self, amount: u64) -> Result<u64> {
+        let fee = (amount as u128)
+            .checked_mul(self.fee_bps as u128)
+            .and_then(|v| v.checked_div(MAX_BPS as u128))
+            .ok_or(BridgeError::MathOverflow)?;
+        u64::try_from(fee).map_err(|_| error!(BridgeError::MathOverflow))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> BridgeConfig {
+        BridgeConfig {
+            fee_bps: 30,
+            min_transfer: 10_000_000,
+            max_transfer: 1_000_000_000_000,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn validates_transfer_limits() {
+        assert!(BridgeConfig::validate_transfer_limits(1, 1, 1).is_ok());
+        assert!(BridgeConfig::validate_transfer_limits(10, 100, 1_000).is_ok());
+        for (min, max, cap, error) in [
+            (0, 100, 1_000, BridgeError::InvalidTransferLimits),
+            (101, 100, 1_000, BridgeError::InvalidTransferLimits),
+            (10, 100, 99, BridgeError::InvalidDailyVolumeCap),
+        ] {
+            assert_eq!(
+                BridgeConfig::validate_transfer_limits(min, max, cap).unwrap_err(),
+                error.into()
+            );
+        }
+    }
+
+    #[test]
+    fn enforces_transfer_limits() {
+        let config = config();
+        assert_eq!(
+            config.check_transfer_amount(9_999_999).unwrap_err(),
+            BridgeError::AmountBelowMinimum.into()
+        );
+        assert!(config.check_transfer_amount(10_000_000).is_ok());
+        assert!(config.check_transfer_amount(1_000_000_000_000).is_ok());
+        assert_eq!(
+            config.check_transfer_amount(1_000_000_000_001).unwrap_err(),
+            BridgeError::AmountAboveMaximum.into()
+        );
+    }
+
+    #[test]
+    fn fee_rounds_down() {
+        let config = config();
+        assert_eq!(config.fee_for(1_000_000).unwrap(), 3_000);
+        assert_eq!(config.fee_for(3_333).unwrap(), 9);
+        assert_eq!(config.fee_for(333).unwrap(), 0);
+        assert_eq!(config.fee_for(u64::MAX).unwrap(), 55_340_232_221_128_654);
+
+        let free = BridgeConfig::default();
+        assert_eq!(free.fee_for(u64::MAX).unwrap(), 0);
+    }
+
+    #[test]
+    fn vault_authority_seeds_sign_for_the_pda() {
+        let (authority, bump) =
+            Pubkey::find_program_address(&[BridgeConfig::VAULT_AUTHORITY_SEED], &crate::ID);
+        let config = BridgeConfig {
+            vault_authority_bump: bump,
+            ..Default::default()
+        };
+        assert_eq!(
+            Pubkey::create_program_address(&config.vault_authority_seeds(), &crate::ID).unwrap(),
+            authority
+        );
+
+        // Each mint gets its own vault.
+        let vault = |mint: &Pubkey| {
+            Pubkey::find_program_address(&[BridgeConfig::VAULT_SEED, mint.as_ref()], &crate::ID).0
+        };
+        assert_ne!(vault(&Pubkey::new_unique()), vault(&Pubkey::new_unique()));
+    }
+}
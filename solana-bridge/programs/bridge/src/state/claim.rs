@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+
+/// Marks a VAA as consumed. One account per (emitter chain, emitter address,
+/// sequence), so each Wormhole message can be redeemed at most once.
+#[account]
+#[derive(InitSpace)]
+pub struct Claim {
+    pub processed: bool,
+    pub transfer_id: [u8; 32],
+    pub processed_at: i64,
+    pub bump: u8,
+}
+
+impl Claim {
+    pub const SEED: &'static [u8] = b"claim";
+
+    pub fn mark_processed(&mut self, transfer_id: [u8; 32], now: i64, bump: u8) -> Result<()> {
+        require!(!self.processed, BridgeError::AlreadyProcessed);
+        self.processed = true;
+        self.transfer_id = transfer_id;
+        self.processed_at = now;
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// Whether `account` holds a processed claim. A claim account that does
+    /// not exist yet belongs to a VAA that has not been redeemed.
+    pub fn is_consumed(account: &AccountInfo) -> Result<bool> {
+        if account.owner != &crate::ID || account.data_is_empty() {
+            return Ok(false);
+        }
+        let data = account.try_borrow_data()?;
+        Ok(Claim::try_deserialize(&mut &data[..])?.processed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn claim() -> Claim {
+        Claim {
+            processed: false,
+            transfer_id: [0; 32],
+            processed_at: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn claim_is_processed_once() {
+        let mut claim = claim();
+        claim.mark_processed([7; 32], 1_000, 254).unwrap();
+        assert!(claim.processed);
+        assert_eq!(claim.transfer_id, [7; 32]);
+        assert_eq!(claim.processed_at, 1_000);
+        assert_eq!(claim.bump, 254);
+
+        assert_eq!(
+            claim.mark_processed([7; 32], 2_000, 254).unwrap_err(),
+            BridgeError::AlreadyProcessed.into()
+        );
+        assert_eq!(claim.processed_at, 1_000);
+    }
+
+    #[test]
+    fn consumed_once_a_withdraw_marks_it() {
+        let key = Pubkey::new_unique();
+        let mut lamports = 1_000_000;
+        let mut empty = [];
+        let missing = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut empty,
+            &crate::ID,
+            false,
+            0,
+        );
+        assert!(!Claim::is_consumed(&missing).unwrap());
+
+        let mut claim = claim();
+        let mut data = Vec::new();
+        claim.try_serialize(&mut data).unwrap();
+        let mut lamports = 1_000_000;
+        let created = AccountInfo::new(
+            &key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &crate::ID,
+            false,
+            0,
+        );
+        assert!(!Claim::is_consumed(&created).unwrap());
+
+        claim.mark_processed([7; 32], 1_000, 254).unwrap();
+        claim
+            .try_serialize(&mut &mut created.try_borrow_mut_data().unwrap()[..])
+            .unwrap();
+        assert!(Claim::is_consumed(&created).unwrap());
+
+        // Only this program's accounts count, whatever their data says.
+        let other = Pubkey::new_unique();
+        let mut lamports = 1_000_000;
+        let mut data = created.try_borrow_data().unwrap().to_vec();
+        let foreign = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &other,
+            false,
+            0,
+        );
+        assert!(!Claim::is_consumed(&foreign).unwrap());
+    }
+}
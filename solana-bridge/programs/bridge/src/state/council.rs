@@ -0,0 +1,244 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+
+use crate::errors::BridgeError;
+
+/// Largest council the account can hold.
+pub const MAX_COUNCIL_MEMBERS: usize = 16;
+
+/// Largest instruction a proposal can carry.
+pub const MAX_PROPOSAL_DATA_LEN: usize = 512;
+
+/// Most accounts a proposed instruction can reference.
+pub const MAX_PROPOSAL_ACCOUNTS: usize = 16;
+
+/// M-of-N council that governs the bridge.
+///
+/// The council acts through its authority PDA, which signs every approved
+/// proposal. Making that PDA `BridgeConfig::admin` hands the council every
+/// admin power, including changing its own membership.
+#[account]
+#[derive(InitSpace)]
+pub struct Council {
+    #[max_len(MAX_COUNCIL_MEMBERS)]
+    pub members: Vec<Pubkey>,
+    /// Approvals needed to execute a proposal.
+    pub threshold: u8,
+    /// Bumped on every membership change; proposals from an older epoch can
+    /// no longer be approved or executed.
+    pub epoch: u32,
+    /// Id of the next proposal.
+    pub proposal_count: u64,
+    pub authority_bump: u8,
+    pub bump: u8,
+}
+
+impl Council {
+    pub const SEED: &'static [u8] = b"council";
+    pub const AUTHORITY_SEED: &'static [u8] = b"council_authority";
+
+    pub fn check_membership(members: &[Pubkey], threshold: u8) -> Result<()> {
+        require!(
+            !members.is_empty() && members.len() <= MAX_COUNCIL_MEMBERS,
+            BridgeError::InvalidCouncil
+        );
+        require!(
+            threshold > 0 && threshold as usize <= members.len(),
+            BridgeError::InvalidCouncil
+        );
+        for (i, member) in members.iter().enumerate() {
+            require!(
+                *member != Pubkey::default() && !members[..i].contains(member),
+                BridgeError::InvalidCouncil
+            );
+        }
+        Ok(())
+    }
+
+    pub fn member_index(&self, key: &Pubkey) -> Result<usize> {
+        self.members
+            .iter()
+            .position(|m| m == key)
+            .ok_or_else(|| error!(BridgeError::NotCouncilMember))
+    }
+
+    /// Signer seeds of the council authority PDA.
+    pub fn authority_seeds(&self) -> [&[u8]; 2] {
+        [
+            Self::AUTHORITY_SEED,
+            std::slice::from_ref(&self.authority_bump),
+        ]
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct ProposalAccount {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+/// A bridge instruction awaiting council approval.
+#[account]
+#[derive(InitSpace)]
+pub struct Proposal {
+    pub id: u64,
+    /// Council epoch the proposal was created in.
+    pub epoch: u32,
+    pub proposer: Pubkey,
+    /// Accounts of the instruction, in order.
+    #[max_len(MAX_PROPOSAL_ACCOUNTS)]
+    pub accounts: Vec<ProposalAccount>,
+    /// Anchor-encoded instruction data for this program.
+    #[max_len(MAX_PROPOSAL_DATA_LEN)]
+    pub data: Vec<u8>,
+    /// Bit `i` is set once `members[i]` has approved.
+    pub approvals: u32,
+    pub executed: bool,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl Proposal {
+    pub const SEED: &'static [u8] = b"proposal";
+
+    pub fn check_size(accounts: &[ProposalAccount], data: &[u8]) -> Result<()> {
+        require!(
+            accounts.len() <= MAX_PROPOSAL_ACCOUNTS && data.len() <= MAX_PROPOSAL_DATA_LEN,
+            BridgeError::ProposalTooLarge
+        );
+        Ok(())
+    }
+
+    /// Rejects proposals that were executed or belong to an older council.
+    pub fn check_open(&self, council: &Council) -> Result<()> {
+        require!(!self.executed, BridgeError::ProposalClosed);
+        require!(self.epoch == council.epoch, BridgeError::ProposalStale);
+        Ok(())
+    }
+
+    pub fn approve(&mut self, member_index: usize) -> Result<()> {
+        let bit = 1u32 << member_index;
+        require!(self.approvals & bit == 0, BridgeError::AlreadyApproved);
+        self.approvals |= bit;
+        Ok(())
+    }
+
+    pub fn approval_count(&self) -> u32 {
+        self.approvals.count_ones()
+    }
+
+    /// The instruction to invoke, checking that `infos` supplies exactly the
+    /// proposed accounts in order.
+    pub fn instruction(&self, infos: &[AccountInfo]) -> Result<Instruction> {
+        require!(
+            infos.len() == self.accounts.len()
+                && infos
+                    .iter()
+                    .zip(&self.accounts)
+                    .all(|(info, account)| *info.key == account.pubkey),
+            BridgeError::ProposalAccountMismatch
+        );
+        Ok(Instruction {
+            program_id: crate::ID,
+            accounts: self
+                .accounts
+                .iter()
+                .map(|a| AccountMeta {
+                    pubkey: a.pubkey,
+                    is_signer: a.is_signer,
+                    is_writable: a.is_writable,
+                })
+                .collect(),
+            data: self.data.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn council(members: usize, threshold: u8) -> Council {
+        Council {
+            members: (0..members).map(|_| Pubkey::new_unique()).collect(),
+            threshold,
+            epoch: 0,
+            proposal_count: 0,
+            authority_bump: 0,
+            bump: 0,
+        }
+    }
+
+    fn proposal() -> Proposal {
+        Proposal {
+            id: 0,
+            epoch: 0,
+            proposer: Pubkey::default(),
+            accounts: vec![],
+            data: vec![],
+            approvals: 0,
+            executed: false,
+            created_at: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn validates_membership() {
+        let c = council(3, 2);
+        assert!(Council::check_membership(&c.members, 2).is_ok());
+        assert!(Council::check_membership(&c.members, 3).is_ok());
+        assert!(Council::check_membership(&c.members, 0).is_err());
+        assert!(Council::check_membership(&c.members, 4).is_err());
+        assert!(Council::check_membership(&[], 0).is_err());
+
+        let duplicate = [c.members[0], c.members[0]];
+        assert_eq!(
+            Council::check_membership(&duplicate, 1).unwrap_err(),
+            BridgeError::InvalidCouncil.into()
+        );
+        assert!(Council::check_membership(&[Pubkey::default()], 1).is_err());
+
+        let too_many = council(MAX_COUNCIL_MEMBERS + 1, 1);
+        assert!(Council::check_membership(&too_many.members, 1).is_err());
+    }
+
+    #[test]
+    fn counts_each_member_once() {
+        let c = council(3, 2);
+        let mut p = proposal();
+        p.approve(c.member_index(&c.members[2]).unwrap()).unwrap();
+        assert_eq!(
+            p.approve(2).unwrap_err(),
+            BridgeError::AlreadyApproved.into()
+        );
+        p.approve(0).unwrap();
+        assert_eq!(p.approval_count(), 2);
+
+        assert_eq!(
+            c.member_index(&Pubkey::new_unique()).unwrap_err(),
+            BridgeError::NotCouncilMember.into()
+        );
+    }
+
+    #[test]
+    fn closes_on_execution_and_membership_change() {
+        let mut c = council(3, 2);
+        let mut p = proposal();
+        assert!(p.check_open(&c).is_ok());
+
+        c.epoch += 1;
+        assert_eq!(
+            p.check_open(&c).unwrap_err(),
+            BridgeError::ProposalStale.into()
+        );
+
+        p.epoch = c.epoch;
+        p.executed = true;
+        assert_eq!(
+            p.check_open(&c).unwrap_err(),
+            BridgeError::ProposalClosed.into()
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::security::PauseFlags;
+use crate::state::SOLANA_CHAIN_ID;
+
+/// Bridge contract trusted to emit transfer messages from one foreign chain.
+#[account]
+#[derive(InitSpace)]
+pub struct ForeignEmitter {
+    /// Wormhole chain id of the foreign chain.
+    pub chain: u16,
+    /// Emitter address, left-padded to 32 bytes as in the VAA.
+    pub address: [u8; 32],
+    /// Halts transfers to (`outbound`) or from (`inbound`) this chain.
+    pub paused: PauseFlags,
+    pub bump: u8,
+}
+
+impl ForeignEmitter {
+    pub const SEED: &'static [u8] = b"foreign_emitter";
+
+    pub fn check_registration(chain: u16, address: &[u8; 32]) -> Result<()> {
+        require!(
+            chain != 0 && chain != SOLANA_CHAIN_ID,
+            BridgeError::InvalidForeignEmitter
+        );
+        require!(*address != [0u8; 32], BridgeError::InvalidForeignEmitter);
+        Ok(())
+    }
+
+    pub fn is_registered(&self, chain: u16, address: &[u8; 32]) -> bool {
+        self.chain == chain && self.address == *address
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::ETHEREUM_CHAIN_ID;
+    use crate::verification::ParsedVaa;
+
+    #[test]
+    fn registration_needs_a_foreign_chain_and_address() {
+        assert!(ForeignEmitter::check_registration(ETHEREUM_CHAIN_ID, &[1; 32]).is_ok());
+        for (chain, address) in [
+            (0, [1; 32]),
+            (SOLANA_CHAIN_ID, [1; 32]),
+            (ETHEREUM_CHAIN_ID, [0; 32]),
+        ] {
+            assert_eq!(
+                ForeignEmitter::check_registration(chain, &address).unwrap_err(),
+                BridgeError::InvalidForeignEmitter.into()
+            );
+        }
+        // A truncated VAA peeks as chain 0, which can never be registered.
+        assert_eq!(ParsedVaa::peek_emitter_chain(&[1, 0, 0]), 0);
+    }
+
+    #[test]
+    fn matches_only_its_chain_and_address() {
+        let mut address = [0u8; 32];
+        address[12..].copy_from_slice(&[0xab; 20]);
+        let emitter = ForeignEmitter {
+            chain: ETHEREUM_CHAIN_ID,
+            address,
+            paused: PauseFlags::default(),
+            bump: 0,
+        };
+        assert!(emitter.is_registered(ETHEREUM_CHAIN_ID, &address));
+        assert!(!emitter.is_registered(ETHEREUM_CHAIN_ID + 1, &address));
+
+        let mut other = address;
+        other[31] ^= 1;
+        assert!(!emitter.is_registered(ETHEREUM_CHAIN_ID, &other));
+    }
+}
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+
+/// Largest guardian set Wormhole has ever used.
+pub const MAX_GUARDIANS: usize = 19;
+
+/// How long a replaced guardian set keeps verifying VAAs, in seconds.
+pub const GUARDIAN_SET_EXPIRATION: i64 = 24 * 60 * 60;
+
+/// Mirror of a Wormhole guardian set, used to verify VAA signatures.
+#[account]
+#[derive(InitSpace)]
+pub struct GuardianSet {
+    pub index: u32,
+    /// Ethereum-style addresses of the guardians, in guardian index order.
+    #[max_len(MAX_GUARDIANS)]
+    pub keys: Vec<[u8; 20]>,
+    pub created_at: i64,
+    /// Unix time after which the set stops verifying; zero while current.
+    pub expiration_time: i64,
+    pub bump: u8,
+}
+
+impl GuardianSet {
+    pub const SEED: &'static [u8] = b"guardian_set";
+
+    /// Signatures needed for a VAA to be valid: more than two thirds.
+    pub fn quorum(&self) -> usize {
+        self.keys.len() * 2 / 3 + 1
+    }
+
+    pub fn is_active(&self, now: i64) -> bool {
+        self.expiration_time == 0 || now < self.expiration_time
+    }
+
+    /// Like Wormhole, sets are numbered from 0 with no gaps. Every set after
+    /// the first must replace the current one, so that it can be expired.
+    pub fn check_next_index(current: u32, index: u32, replaces_current: bool) -> Result<()> {
+        let expected = if replaces_current {
+            current.checked_add(1)
+        } else {
+            // Set 0 is created once; a second attempt fails at its `init`.
+            Some(0)
+        };
+        require!(
+            expected == Some(index),
+            BridgeError::InvalidGuardianSetIndex
+        );
+        Ok(())
+    }
+
+    pub fn check_keys(keys: &[[u8; 20]]) -> Result<()> {
+        require!(
+            !keys.is_empty() && keys.len() <= MAX_GUARDIANS,
+            BridgeError::InvalidGuardianSet
+        );
+        for (i, key) in keys.iter().enumerate() {
+            require!(*key != [0u8; 20], BridgeError::InvalidGuardianSet);
+            require!(!keys[..i].contains(key), BridgeError::InvalidGuardianSet);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sets_follow_the_current_one() {
+        assert!(GuardianSet::check_next_index(0, 0, false).is_ok());
+        assert!(GuardianSet::check_next_index(0, 1, true).is_ok());
+        assert!(GuardianSet::check_next_index(4, 5, true).is_ok());
+
+        for (current, index, replaces_current) in [
+            // A later set must expire the one it replaces.
+            (0, 1, false),
+            (4, 5, false),
+            // No re-targeting the current index, going back or skipping.
+            (4, 4, true),
+            (4, 3, true),
+            (4, 6, true),
+            (u32::MAX, 0, true),
+        ] {
+            assert_eq!(
+                GuardianSet::check_next_index(current, index, replaces_current).unwrap_err(),
+                BridgeError::InvalidGuardianSetIndex.into(),
+                "{current} -> {index}"
+            );
+        }
+    }
+
+    #[test]
+    fn replaced_set_expires() {
+        let mut set = GuardianSet {
+            index: 0,
+            keys: vec![[1; 20]],
+            created_at: 0,
+            expiration_time: 0,
+            bump: 0,
+        };
+        assert!(set.is_active(i64::MAX));
+        set.expiration_time = 1_000 + GUARDIAN_SET_EXPIRATION;
+        assert!(set.is_active(1_000 + GUARDIAN_SET_EXPIRATION - 1));
+        assert!(!set.is_active(1_000 + GUARDIAN_SET_EXPIRATION));
+    }
+}
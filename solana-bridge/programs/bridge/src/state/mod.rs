@@ -1,5 +1,21 @@
 pub mod bridge_config;
+pub mod claim;
+pub mod council;
+pub mod foreign_emitter;
+pub mod guardian_set;
+pub mod pending_release;
+pub mod token_ledger;
+pub mod token_registry;
 pub mod transfer;
+pub mod wormhole_emitter;
 
 pub use bridge_config::*;
+pub use claim::*;
+pub use council::*;
+pub use foreign_emitter::*;
+pub use guardian_set::*;
+pub use pending_release::*;
+pub use token_ledger::*;
+pub use token_registry::*;
 pub use transfer::*;
+pub use wormhole_emitter::*;
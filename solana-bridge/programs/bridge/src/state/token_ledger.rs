@@ -0,0 +1,209 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+
+/// Running totals of everything that moved through one mint's vault, in
+/// token units. One account per mint, created with its registry entry.
+///
+/// From these the ledger knows what the vault must hold; `assert_solvency`
+/// compares that with the real balance. Tokens sent to the vault outside the
+/// bridge are not tracked and only show up as surplus.
+#[account]
+#[derive(InitSpace)]
+pub struct TokenLedger {
+    pub mint: Pubkey,
+    /// Liquidity added through `fund_vault`.
+    pub total_funded: u64,
+    /// Gross amounts locked by deposits, fees included.
+    pub total_deposited: u64,
+    /// Amounts paid out to inbound recipients.
+    pub total_released: u64,
+    /// Deposit fees charged since the token was added.
+    pub total_fees: u64,
+    pub fees_withdrawn: u64,
+    /// Owed to recipients but still held in the vault, e.g. pending releases.
+    pub in_flight: u64,
+    pub bump: u8,
+}
+
+impl TokenLedger {
+    pub const SEED: &'static [u8] = b"ledger";
+
+    pub fn record_funding(&mut self, amount: u64) -> Result<()> {
+        self.total_funded = add(self.total_funded, amount)?;
+        Ok(())
+    }
+
+    pub fn record_deposit(&mut self, amount: u64, fee: u64) -> Result<()> {
+        self.total_deposited = add(self.total_deposited, amount)?;
+        self.total_fees = add(self.total_fees, fee)?;
+        Ok(())
+    }
+
+    pub fn record_release(&mut self, amount: u64) -> Result<()> {
+        self.total_released = add(self.total_released, amount)?;
+        Ok(())
+    }
+
+    /// Fees charged and not yet withdrawn. The ledger is the only record of
+    /// them; nothing else tracks accrued fees.
+    pub fn fees_owed(&self) -> u64 {
+        self.total_fees.saturating_sub(self.fees_withdrawn)
+    }
+
+    pub fn record_fee_withdrawal(&mut self, amount: u64) -> Result<()> {
+        require!(
+            amount <= self.fees_owed(),
+            BridgeError::InsufficientAccruedFees
+        );
+        self.fees_withdrawn = add(self.fees_withdrawn, amount)?;
+        Ok(())
+    }
+
+    /// Marks `amount` as owed but not yet paid.
+    pub fn hold(&mut self, amount: u64) -> Result<()> {
+        self.in_flight = add(self.in_flight, amount)?;
+        Ok(())
+    }
+
+    /// Pays out a held amount.
+    pub fn release_held(&mut self, amount: u64) -> Result<()> {
+        self.drop_held(amount)?;
+        self.record_release(amount)
+    }
+
+    /// Forgets a held amount that will not be paid.
+    pub fn drop_held(&mut self, amount: u64) -> Result<()> {
+        self.in_flight = self
+            .in_flight
+            .checked_sub(amount)
+            .ok_or(BridgeError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// What the vault must hold, or `None` if more went out than came in.
+    pub fn expected_balance(&self) -> Option<u64> {
+        let inflow = self.total_funded as u128 + self.total_deposited as u128;
+        let outflow = self.total_released as u128 + self.fees_withdrawn as u128;
+        inflow
+            .checked_sub(outflow)
+            .and_then(|balance| u64::try_from(balance).ok())
+    }
+
+    /// Amounts the vault must be able to pay out: held releases and fees not
+    /// yet withdrawn.
+    pub fn obligations(&self) -> u128 {
+        self.in_flight as u128 + self.fees_owed() as u128
+    }
+
+    /// True if `vault_balance` covers the expected balance, and the expected
+    /// balance covers every obligation.
+    pub fn is_solvent(&self, vault_balance: u64) -> bool {
+        self.expected_balance().is_some_and(|expected| {
+            vault_balance >= expected && expected as u128 >= self.obligations()
+        })
+    }
+}
+
+fn add(total: u64, amount: u64) -> Result<u64> {
+    total
+        .checked_add(amount)
+        .ok_or_else(|| error!(BridgeError::MathOverflow))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ledger() -> TokenLedger {
+        TokenLedger {
+            mint: Pubkey::default(),
+            total_funded: 0,
+            total_deposited: 0,
+            total_released: 0,
+            total_fees: 0,
+            fees_withdrawn: 0,
+            in_flight: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn matches_vault_after_100_transfers() {
+        let mut ledger = ledger();
+        let mut vault: u64 = 0;
+
+        ledger.record_funding(1_000_000).unwrap();
+        vault += 1_000_000;
+        for i in 0..100u64 {
+            let amount = 10_000 + i * 37;
+            if i % 2 == 0 {
+                let fee = amount * 30 / 10_000;
+                ledger.record_deposit(amount, fee).unwrap();
+                vault += amount;
+            } else if i % 5 == 0 {
+                ledger.hold(amount).unwrap();
+                ledger.release_held(amount).unwrap();
+                vault -= amount;
+            } else {
+                ledger.record_release(amount).unwrap();
+                vault -= amount;
+            }
+            assert_eq!(ledger.expected_balance(), Some(vault));
+            assert!(ledger.is_solvent(vault));
+        }
+
+        let fees = ledger.total_fees;
+        ledger.record_fee_withdrawal(fees).unwrap();
+        vault -= fees;
+        assert_eq!(ledger.expected_balance(), Some(vault));
+        assert!(ledger.is_solvent(vault));
+    }
+
+    #[test]
+    fn fee_withdrawals_are_capped_by_fees_owed() {
+        let mut ledger = ledger();
+        ledger.record_deposit(10_000, 30).unwrap();
+        ledger.record_deposit(20_000, 60).unwrap();
+        assert_eq!(ledger.fees_owed(), 90);
+
+        ledger.record_fee_withdrawal(50).unwrap();
+        assert_eq!(ledger.fees_owed(), 40);
+        assert_eq!(
+            ledger.record_fee_withdrawal(41).unwrap_err(),
+            BridgeError::InsufficientAccruedFees.into()
+        );
+        ledger.record_fee_withdrawal(40).unwrap();
+        assert_eq!(ledger.fees_owed(), 0);
+        assert_eq!((ledger.total_fees, ledger.fees_withdrawn), (90, 90));
+    }
+
+    #[test]
+    fn shortfall_is_insolvent_and_surplus_is_not() {
+        let mut ledger = ledger();
+        ledger.record_deposit(1_000, 10).unwrap();
+        assert!(ledger.is_solvent(1_000));
+        assert!(ledger.is_solvent(1_500));
+        assert!(!ledger.is_solvent(999));
+    }
+
+    #[test]
+    fn obligations_must_be_covered() {
+        let mut ledger = ledger();
+        ledger.record_deposit(1_000, 100).unwrap();
+        ledger.hold(900).unwrap();
+        assert!(ledger.is_solvent(1_000));
+
+        // Paying out more than came in can never be solvent.
+        ledger.record_release(950).unwrap();
+        assert!(!ledger.is_solvent(1_000));
+        ledger.record_release(100).unwrap();
+        assert_eq!(ledger.expected_balance(), None);
+        assert!(!ledger.is_solvent(u64::MAX));
+
+        assert_eq!(
+            ledger.drop_held(901).unwrap_err(),
+            BridgeError::MathOverflow.into()
+        );
+    }
+}
@@ -0,0 +1,174 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::security::{PauseFlags, PriceGuard};
+
+/// Decimals of the canonical USD amounts carried in transfer messages.
+pub const USD_DECIMALS: u8 = 6;
+
+/// Largest mint precision the registry can normalize.
+pub const MAX_TOKEN_DECIMALS: u8 = 18;
+
+/// Registry entry for a whitelisted mint. One account per mint.
+#[account]
+#[derive(InitSpace)]
+pub struct SupportedToken {
+    pub mint: Pubkey,
+    /// Decimals of `mint`, copied when the entry is created.
+    pub decimals: u8,
+    /// Smallest accepted transfer of this token, in USD (6 decimals).
+    pub min_amount_usd: u64,
+    /// Largest accepted transfer of this token, in USD (6 decimals).
+    pub max_amount_usd: u64,
+    pub enabled: bool,
+    /// Temporary halts of this token, set by a pauser or admin.
+    pub paused: PauseFlags,
+    /// Depeg protection; disabled unless a price feed is set.
+    pub price_guard: PriceGuard,
+    /// Pools `withdraw` may swap through to pay out in this mint.
+    pub swap_pools: SwapPools,
+    /// Bump of this mint's vault token account.
+    pub vault_bump: u8,
+    pub bump: u8,
+}
+
+/// Swap venues pinned for a token, set by an operator. A default key allows
+/// no swaps through that venue.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace,
+)]
+pub struct SwapPools {
+    /// Orca whirlpool pairing this mint with another registered stablecoin.
+    pub whirlpool: Pubkey,
+    /// amm StableSwap pool pairing this mint with another registered
+    /// stablecoin.
+    pub stable_pool: Pubkey,
+}
+
+impl SupportedToken {
+    pub const SEED: &'static [u8] = b"token";
+
+    pub fn check_enabled(&self) -> Result<()> {
+        require!(self.enabled, BridgeError::TokenDisabled);
+        Ok(())
+    }
+
+    /// Rejects USD amounts outside this token's limits.
+    pub fn check_amount_usd(&self, amount_usd: u64) -> Result<()> {
+        require!(
+            amount_usd >= self.min_amount_usd,
+            BridgeError::AmountBelowMinimum
+        );
+        require!(
+            amount_usd <= self.max_amount_usd,
+            BridgeError::AmountAboveMaximum
+        );
+        Ok(())
+    }
+
+    /// Converts a token amount to USD with 6 decimals, rounding down.
+    pub fn to_usd(&self, amount: u64) -> Result<u64> {
+        rescale(amount, self.decimals, USD_DECIMALS)
+    }
+
+    /// Converts a USD amount with 6 decimals to token units, rounding down.
+    pub fn from_usd(&self, amount_usd: u64) -> Result<u64> {
+        rescale(amount_usd, USD_DECIMALS, self.decimals)
+    }
+}
+
+fn rescale(amount: u64, from_decimals: u8, to_decimals: u8) -> Result<u64> {
+    let scaled = if to_decimals >= from_decimals {
+        10u128
+            .checked_pow((to_decimals - from_decimals) as u32)
+            .and_then(|factor| (amount as u128).checked_mul(factor))
+    } else {
+        10u128
+            .checked_pow((from_decimals - to_decimals) as u32)
+            .and_then(|factor| (amount as u128).checked_div(factor))
+    }
+    .ok_or(BridgeError::MathOverflow)?;
+    u64::try_from(scaled).map_err(|_| error!(BridgeError::MathOverflow))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(decimals: u8) -> SupportedToken {
+        SupportedToken {
+            mint: Pubkey::default(),
+            decimals,
+            min_amount_usd: 10_000_000,
+            max_amount_usd: 1_000_000_000_000,
+            enabled: true,
+            paused: PauseFlags::default(),
+            price_guard: PriceGuard::default(),
+            swap_pools: SwapPools::default(),
+            vault_bump: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn normalizes_common_decimals() {
+        // (decimals, token amount worth $1.234567 plus dust, dust-free amount)
+        for (decimals, amount, exact) in [
+            (6, 1_234_567, 1_234_567),
+            (8, 123_456_789, 123_456_700),
+            (9, 1_234_567_999, 1_234_567_000),
+            (18, 1_234_567_999_999_999_999, 1_234_567_000_000_000_000),
+        ] {
+            let token = token(decimals);
+            assert_eq!(token.to_usd(amount).unwrap(), 1_234_567, "{decimals}");
+            assert_eq!(token.from_usd(1_234_567).unwrap(), exact, "{decimals}");
+        }
+
+        let cents = token(2);
+        assert_eq!(cents.to_usd(150).unwrap(), 1_500_000);
+        assert_eq!(cents.from_usd(1_239_999).unwrap(), 123);
+    }
+
+    #[test]
+    fn rounding_never_favors_the_withdrawer() {
+        for decimals in [0, 2, 6, 8, 9, 18] {
+            let token = token(decimals);
+            for amount_usd in [0, 1, 999_999, 1_000_001, 12_345_678] {
+                let released = token.from_usd(amount_usd).unwrap();
+                assert!(token.to_usd(released).unwrap() <= amount_usd, "{decimals}");
+            }
+        }
+    }
+
+    #[test]
+    fn overflow_is_an_error() {
+        assert_eq!(token(6).to_usd(u64::MAX).unwrap(), u64::MAX);
+        assert_eq!(token(18).to_usd(u64::MAX).unwrap(), 18_446_744);
+        assert_eq!(
+            token(18).from_usd(18_446_744).unwrap(),
+            18_446_744_000_000_000_000
+        );
+        for error in [
+            token(18).from_usd(18_446_745),
+            token(8).from_usd(u64::MAX),
+            token(2).to_usd(u64::MAX),
+        ] {
+            assert_eq!(error.unwrap_err(), BridgeError::MathOverflow.into());
+        }
+    }
+
+    #[test]
+    fn enforces_usd_limits() {
+        let token = token(6);
+        assert_eq!(
+            token.check_amount_usd(9_999_999).unwrap_err(),
+            BridgeError::AmountBelowMinimum.into()
+        );
+        assert!(token.check_amount_usd(10_000_000).is_ok());
+        assert!(token.check_amount_usd(1_000_000_000_000).is_ok());
+        assert_eq!(
+            token.check_amount_usd(1_000_000_000_001).unwrap_err(),
+            BridgeError::AmountAboveMaximum.into()
+        );
+    }
+}
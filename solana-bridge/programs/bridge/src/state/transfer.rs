@@ -0,0 +1,218 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+
+use crate::errors::BridgeError;
+
+/// Wormhole chain id of Solana.
+pub const SOLANA_CHAIN_ID: u16 = 1;
+
+/// Wormhole chain id of Ethereum.
+pub const ETHEREUM_CHAIN_ID: u16 = 2;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum TransferDirection {
+    /// Tokens locked on Solana, to be released on another chain.
+    Outbound,
+    /// Tokens released on Solana for a transfer started on another chain.
+    Inbound,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum TransferStatus {
+    /// Deposit locked and message emitted; awaiting the destination chain.
+    Initiated,
+    /// Message accepted on the destination chain; funds not yet released.
+    Delivered,
+    /// Funds released to the recipient.
+    Completed,
+    /// Settlement failed and the sender may reclaim the funds.
+    Refundable,
+    /// Funds returned to the sender.
+    Refunded,
+    /// Transfer aborted with no funds released or returned.
+    Failed,
+}
+
+impl TransferStatus {
+    pub fn can_transition_to(self, next: TransferStatus) -> bool {
+        use TransferStatus::*;
+
+        matches!(
+            (self, next),
+            (Initiated, Delivered)
+                | (Initiated, Refundable)
+                | (Initiated, Failed)
+                | (Delivered, Completed)
+                | (Delivered, Refundable)
+                | (Delivered, Failed)
+                | (Refundable, Refunded)
+        )
+    }
+
+    pub fn is_final(self) -> bool {
+        matches!(
+            self,
+            TransferStatus::Completed | TransferStatus::Refunded | TransferStatus::Failed
+        )
+    }
+}
+
+/// On-chain record of a single transfer, kept for its whole lifecycle.
+#[account]
+#[derive(InitSpace)]
+pub struct TransferRecord {
+    pub transfer_id: [u8; 32],
+    pub direction: TransferDirection,
+    pub status: TransferStatus,
+    /// Mint locked or released on Solana.
+    pub mint: Pubkey,
+    /// Depositor for outbound transfers, recipient for inbound transfers.
+    pub local_account: Pubkey,
+    /// Address on the other chain, left-padded to 32 bytes.
+    pub counterparty: [u8; 32],
+    /// Token amount locked or released on Solana.
+    pub amount: u64,
+    /// Bridge fee withheld from `amount`.
+    pub fee: u64,
+    /// Net USD value carried in the transfer message (6 decimals).
+    pub amount_usd: u64,
+    pub source_chain: u16,
+    pub destination_chain: u16,
+    /// Wormhole sequence of the outbound message; zero for inbound transfers.
+    pub sequence: u64,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl TransferRecord {
+    pub const SEED: &'static [u8] = b"transfer";
+
+    /// Deterministic id of an outbound transfer.
+    pub fn outbound_id(nonce: u64, depositor: &Pubkey) -> [u8; 32] {
+        keccak::hashv(&[
+            &SOLANA_CHAIN_ID.to_be_bytes(),
+            depositor.as_ref(),
+            &nonce.to_be_bytes(),
+        ])
+        .to_bytes()
+    }
+
+    /// Id of the transfer that returns an inbound transfer to its sender.
+    pub fn refund_id(transfer_id: &[u8; 32]) -> [u8; 32] {
+        keccak::hashv(&[b"refund", transfer_id]).to_bytes()
+    }
+
+    pub fn transition(&mut self, next: TransferStatus, now: i64) -> Result<()> {
+        if self.status.is_final() {
+            msg!("Transfer is already {:?}", self.status);
+            return err!(BridgeError::InvalidTransferTransition);
+        }
+        require!(
+            self.status.can_transition_to(next),
+            BridgeError::InvalidTransferTransition
+        );
+        self.status = next;
+        self.updated_at = now;
+        Ok(())
+    }
+}
+
+/// Left-pads a 20- or 32-byte foreign address into a 32-byte slot.
+pub fn to_universal_address(address: &[u8]) -> Result<[u8; 32]> {
+    require!(
+        address.len() == 20 || address.len() == 32,
+        BridgeError::InvalidAddressLength
+    );
+    let mut out = [0u8; 32];
+    out[32 - address.len()..].copy_from_slice(address);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use TransferStatus::*;
+
+    const ALL: [TransferStatus; 6] = [
+        Initiated, Delivered, Completed, Refundable, Refunded, Failed,
+    ];
+
+    #[test]
+    fn transition_table() {
+        let allowed = [
+            (Initiated, Delivered),
+            (Initiated, Refundable),
+            (Initiated, Failed),
+            (Delivered, Completed),
+            (Delivered, Refundable),
+            (Delivered, Failed),
+            (Refundable, Refunded),
+        ];
+        for from in ALL {
+            for to in ALL {
+                assert_eq!(
+                    from.can_transition_to(to),
+                    allowed.contains(&(from, to)),
+                    "{from:?} -> {to:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn final_states_are_terminal() {
+        for from in ALL {
+            let terminal = ALL.iter().all(|to| !from.can_transition_to(*to));
+            assert_eq!(from.is_final(), terminal, "{from:?}");
+        }
+        assert!(Completed.is_final() && Refunded.is_final() && Failed.is_final());
+        assert!(!Refundable.is_final());
+    }
+
+    #[test]
+    fn record_transitions_stamp_the_time() {
+        let mut record = TransferRecord {
+            transfer_id: [0; 32],
+            direction: TransferDirection::Inbound,
+            status: Delivered,
+            mint: Pubkey::default(),
+            local_account: Pubkey::default(),
+            counterparty: [0; 32],
+            amount: 0,
+            fee: 0,
+            amount_usd: 0,
+            source_chain: ETHEREUM_CHAIN_ID,
+            destination_chain: SOLANA_CHAIN_ID,
+            sequence: 0,
+            created_at: 0,
+            updated_at: 0,
+            bump: 0,
+        };
+        assert_eq!(
+            record.transition(Refunded, 5).unwrap_err(),
+            BridgeError::InvalidTransferTransition.into()
+        );
+        assert_eq!((record.status, record.updated_at), (Delivered, 0));
+
+        record.transition(Refundable, 10).unwrap();
+        record.transition(Refunded, 20).unwrap();
+        assert_eq!((record.status, record.updated_at), (Refunded, 20));
+        for next in ALL {
+            assert!(record.transition(next, 30).is_err());
+        }
+        assert_eq!(record.updated_at, 20);
+    }
+
+    #[test]
+    fn pads_foreign_addresses() {
+        let mut expected = [0u8; 32];
+        expected[12..].copy_from_slice(&[0xab; 20]);
+        assert_eq!(to_universal_address(&[0xab; 20]).unwrap(), expected);
+        assert_eq!(to_universal_address(&[0xcd; 32]).unwrap(), [0xcd; 32]);
+        assert_eq!(
+            to_universal_address(&[1; 31]).unwrap_err(),
+            BridgeError::InvalidAddressLength.into()
+        );
+    }
+}
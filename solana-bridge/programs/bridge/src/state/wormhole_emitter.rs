@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+/// Program-owned PDA that signs outbound Wormhole messages.
+#[account]
+#[derive(InitSpace)]
+pub struct WormholeEmitter {
+    /// Sequence of the next message; mirrors the core bridge counter.
+    pub sequence: u64,
+    pub bump: u8,
+}
+
+impl WormholeEmitter {
+    pub const SEED: &'static [u8] = b"emitter";
+    /// Seed prefix of the message accounts this emitter posts.
+    pub const MESSAGE_SEED: &'static [u8] = b"sent";
+}
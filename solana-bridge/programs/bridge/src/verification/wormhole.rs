@@ -0,0 +1,336 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{keccak, secp256k1_recover::secp256k1_recover};
+
+use crate::errors::BridgeError;
+use crate::state::GuardianSet;
+
+/// Only VAA version understood by the Wormhole core contracts.
+pub const VAA_VERSION: u8 = 1;
+
+/// Bytes before the signatures: version, guardian set index, signature count.
+const HEADER_LEN: usize = 1 + 4 + 1;
+
+/// Guardian index, r, s and recovery id.
+const SIGNATURE_LEN: usize = 1 + 64 + 1;
+
+/// Body bytes before the payload.
+const BODY_HEADER_LEN: usize = 4 + 4 + 2 + 32 + 8 + 1;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GuardianSignature {
+    pub guardian_index: u8,
+    /// `r || s`.
+    pub signature: [u8; 64],
+    pub recovery_id: u8,
+}
+
+/// A decoded, not yet verified, Wormhole VAA.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParsedVaa {
+    pub version: u8,
+    pub guardian_set_index: u32,
+    pub signatures: Vec<GuardianSignature>,
+    pub timestamp: u32,
+    pub nonce: u32,
+    pub emitter_chain: u16,
+    pub emitter_address: [u8; 32],
+    pub sequence: u64,
+    pub consistency_level: u8,
+    pub payload: Vec<u8>,
+    /// `keccak256(keccak256(body))`, the hash the guardians sign.
+    pub digest: [u8; 32],
+}
+
+impl ParsedVaa {
+    pub fn parse(bytes: &[u8]) -> Result<Self> {
+        require!(bytes.len() >= HEADER_LEN, BridgeError::TruncatedVaa);
+        let version = bytes[0];
+        require!(version == VAA_VERSION, BridgeError::UnsupportedVaaVersion);
+        let guardian_set_index = u32::from_be_bytes(bytes[1..5].try_into().unwrap());
+        let signature_count = bytes[5] as usize;
+
+        let body_start = HEADER_LEN + signature_count * SIGNATURE_LEN;
+        require!(
+            bytes.len() >= body_start + BODY_HEADER_LEN,
+            BridgeError::TruncatedVaa
+        );
+
+        let signatures = bytes[HEADER_LEN..body_start]
+            .chunks_exact(SIGNATURE_LEN)
+            .map(|chunk| GuardianSignature {
+                guardian_index: chunk[0],
+                signature: chunk[1..65].try_into().unwrap(),
+                recovery_id: chunk[65],
+            })
+            .collect();
+
+        let body = &bytes[body_start..];
+        let digest = keccak::hash(&keccak::hash(body).to_bytes()).to_bytes();
+
+        Ok(Self {
+            version,
+            guardian_set_index,
+            signatures,
+            timestamp: u32::from_be_bytes(body[0..4].try_into().unwrap()),
+            nonce: u32::from_be_bytes(body[4..8].try_into().unwrap()),
+            emitter_chain: u16::from_be_bytes(body[8..10].try_into().unwrap()),
+            emitter_address: body[10..42].try_into().unwrap(),
+            sequence: u64::from_be_bytes(body[42..50].try_into().unwrap()),
+            consistency_level: body[50],
+            payload: body[BODY_HEADER_LEN..].to_vec(),
+            digest,
+        })
+    }
+
+    /// Reads the guardian set index without a full parse, for account seeds.
+    pub fn peek_guardian_set_index(bytes: &[u8]) -> u32 {
+        bytes
+            .get(1..5)
+            .map(|b| u32::from_be_bytes(b.try_into().unwrap()))
+            .unwrap_or_default()
+    }
+
+    /// Reads the emitter chain without a full parse, for account seeds.
+    pub fn peek_emitter_chain(bytes: &[u8]) -> u16 {
+        peek_body(bytes)
+            .map(|body| u16::from_be_bytes(body[8..10].try_into().unwrap()))
+            .unwrap_or_default()
+    }
+
+    /// Reads the emitter address without a full parse, for account seeds.
+    pub fn peek_emitter_address(bytes: &[u8]) -> [u8; 32] {
+        peek_body(bytes)
+            .map(|body| body[10..42].try_into().unwrap())
+            .unwrap_or_default()
+    }
+
+    /// Reads the sequence without a full parse, for account seeds.
+    pub fn peek_sequence(bytes: &[u8]) -> u64 {
+        peek_body(bytes)
+            .map(|body| u64::from_be_bytes(body[42..50].try_into().unwrap()))
+            .unwrap_or_default()
+    }
+
+    /// Returns the payload without a full parse, for account seeds.
+    /// Malformed input yields an empty slice and is rejected by `parse`.
+    pub fn peek_payload(bytes: &[u8]) -> &[u8] {
+        peek_body(bytes)
+            .map(|body| &body[BODY_HEADER_LEN..])
+            .unwrap_or_default()
+    }
+
+    /// Checks that a quorum of `guardian_set` signed this VAA.
+    pub fn verify(&self, guardian_set: &GuardianSet, now: i64) -> Result<()> {
+        require!(
+            self.guardian_set_index == guardian_set.index,
+            BridgeError::GuardianSetMismatch
+        );
+        require!(
+            guardian_set.is_active(now),
+            BridgeError::GuardianSetExpired
+        );
+        require!(
+            self.signatures.len() >= guardian_set.quorum(),
+            BridgeError::InsufficientGuardianSignatures
+        );
+
+        let mut last_index: Option<u8> = None;
+        for sig in &self.signatures {
+            require!(
+                last_index.is_none_or(|last| sig.guardian_index > last),
+                BridgeError::SignaturesNotAscending
+            );
+            last_index = Some(sig.guardian_index);
+
+            let expected = guardian_set
+                .keys
+                .get(sig.guardian_index as usize)
+                .ok_or(BridgeError::GuardianIndexOutOfRange)?;
+            let recovered = secp256k1_recover(&self.digest, sig.recovery_id, &sig.signature)
+                .map_err(|_| error!(BridgeError::InvalidGuardianSignature))?;
+            require!(
+                eth_address(&recovered.to_bytes()) == *expected,
+                BridgeError::InvalidGuardianSignature
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Body of a VAA, if it is long enough to hold the body header.
+fn peek_body(bytes: &[u8]) -> Option<&[u8]> {
+    let count = *bytes.get(HEADER_LEN - 1)? as usize;
+    let body = bytes.get(HEADER_LEN + count * SIGNATURE_LEN..)?;
+    (body.len() >= BODY_HEADER_LEN).then_some(body)
+}
+
+/// Ethereum address of an uncompressed secp256k1 public key (without prefix).
+pub fn eth_address(pubkey: &[u8; 64]) -> [u8; 20] {
+    let hash = keccak::hash(pubkey).to_bytes();
+    hash[12..].try_into().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Guardian(libsecp256k1::SecretKey);
+
+    impl Guardian {
+        fn new(seed: u8) -> Self {
+            Self(libsecp256k1::SecretKey::parse(&[seed; 32]).unwrap())
+        }
+
+        fn address(&self) -> [u8; 20] {
+            let public = libsecp256k1::PublicKey::from_secret_key(&self.0).serialize();
+            eth_address(public[1..].try_into().unwrap())
+        }
+
+        fn sign(&self, index: u8, digest: &[u8; 32]) -> Vec<u8> {
+            let message = libsecp256k1::Message::parse(digest);
+            let (signature, recovery_id) = libsecp256k1::sign(&message, &self.0);
+            let mut out = vec![index];
+            out.extend_from_slice(&signature.serialize());
+            out.push(recovery_id.serialize());
+            out
+        }
+    }
+
+    fn body(payload: &[u8]) -> Vec<u8> {
+        let mut body = Vec::new();
+        body.extend_from_slice(&1_700_000_000u32.to_be_bytes());
+        body.extend_from_slice(&7u32.to_be_bytes());
+        body.extend_from_slice(&2u16.to_be_bytes());
+        body.extend_from_slice(&[0xee; 32]);
+        body.extend_from_slice(&99u64.to_be_bytes());
+        body.push(15);
+        body.extend_from_slice(payload);
+        body
+    }
+
+    fn build_vaa(set_index: u32, signers: &[(u8, &Guardian)], payload: &[u8]) -> Vec<u8> {
+        let body = body(payload);
+        let digest = keccak::hash(&keccak::hash(&body).to_bytes()).to_bytes();
+        let mut vaa = vec![VAA_VERSION];
+        vaa.extend_from_slice(&set_index.to_be_bytes());
+        vaa.push(signers.len() as u8);
+        for (index, guardian) in signers {
+            vaa.extend_from_slice(&guardian.sign(*index, &digest));
+        }
+        vaa.extend_from_slice(&body);
+        vaa
+    }
+
+    fn guardian_set(guardians: &[Guardian]) -> GuardianSet {
+        GuardianSet {
+            index: 3,
+            keys: guardians.iter().map(Guardian::address).collect(),
+            created_at: 0,
+            expiration_time: 0,
+            bump: 255,
+        }
+    }
+
+    fn guardians() -> Vec<Guardian> {
+        (1..=4).map(Guardian::new).collect()
+    }
+
+    #[test]
+    fn parses_all_fields() {
+        let g = guardians();
+        let bytes = build_vaa(3, &[(0, &g[0]), (2, &g[2])], b"hello");
+        let vaa = ParsedVaa::parse(&bytes).unwrap();
+
+        assert_eq!(vaa.version, 1);
+        assert_eq!(vaa.guardian_set_index, 3);
+        assert_eq!(vaa.signatures.len(), 2);
+        assert_eq!(vaa.signatures[1].guardian_index, 2);
+        assert_eq!(vaa.timestamp, 1_700_000_000);
+        assert_eq!(vaa.nonce, 7);
+        assert_eq!(vaa.emitter_chain, 2);
+        assert_eq!(vaa.emitter_address, [0xee; 32]);
+        assert_eq!(vaa.sequence, 99);
+        assert_eq!(vaa.consistency_level, 15);
+        assert_eq!(vaa.payload, b"hello");
+        assert_eq!(ParsedVaa::peek_guardian_set_index(&bytes), 3);
+        assert_eq!(ParsedVaa::peek_emitter_chain(&bytes), 2);
+        assert_eq!(ParsedVaa::peek_emitter_address(&bytes), [0xee; 32]);
+        assert_eq!(ParsedVaa::peek_sequence(&bytes), 99);
+        assert_eq!(ParsedVaa::peek_payload(&bytes), b"hello");
+    }
+
+    #[test]
+    fn rejects_malformed_vaas() {
+        let g = guardians();
+        let bytes = build_vaa(3, &[(0, &g[0])], b"");
+
+        let mut bad_version = bytes.clone();
+        bad_version[0] = 2;
+        assert_eq!(
+            ParsedVaa::parse(&bad_version).unwrap_err(),
+            BridgeError::UnsupportedVaaVersion.into()
+        );
+        assert_eq!(
+            ParsedVaa::parse(&bytes[..bytes.len() - 1]).unwrap_err(),
+            BridgeError::TruncatedVaa.into()
+        );
+        assert_eq!(
+            ParsedVaa::parse(&bytes[..3]).unwrap_err(),
+            BridgeError::TruncatedVaa.into()
+        );
+        assert!(ParsedVaa::peek_payload(&bytes[..3]).is_empty());
+    }
+
+    #[test]
+    fn verifies_quorum() {
+        let g = guardians();
+        let set = guardian_set(&g);
+        assert_eq!(set.quorum(), 3);
+
+        let bytes = build_vaa(3, &[(0, &g[0]), (1, &g[1]), (3, &g[3])], b"ok");
+        ParsedVaa::parse(&bytes).unwrap().verify(&set, 0).unwrap();
+    }
+
+    #[test]
+    fn rejects_bad_signatures() {
+        let g = guardians();
+        let set = guardian_set(&g);
+        let verify = |bytes: Vec<u8>| ParsedVaa::parse(&bytes).unwrap().verify(&set, 0);
+
+        assert_eq!(
+            verify(build_vaa(3, &[(0, &g[0]), (1, &g[1])], b"")).unwrap_err(),
+            BridgeError::InsufficientGuardianSignatures.into()
+        );
+        assert_eq!(
+            verify(build_vaa(3, &[(0, &g[0]), (0, &g[0]), (1, &g[1])], b"")).unwrap_err(),
+            BridgeError::SignaturesNotAscending.into()
+        );
+        assert_eq!(
+            verify(build_vaa(3, &[(0, &g[0]), (1, &g[2]), (2, &g[2])], b"")).unwrap_err(),
+            BridgeError::InvalidGuardianSignature.into()
+        );
+        assert_eq!(
+            verify(build_vaa(3, &[(0, &g[0]), (1, &g[1]), (4, &g[3])], b"")).unwrap_err(),
+            BridgeError::GuardianIndexOutOfRange.into()
+        );
+        assert_eq!(
+            verify(build_vaa(4, &[(0, &g[0]), (1, &g[1]), (2, &g[2])], b"")).unwrap_err(),
+            BridgeError::GuardianSetMismatch.into()
+        );
+    }
+
+    #[test]
+    fn rejects_expired_guardian_set() {
+        let g = guardians();
+        let mut set = guardian_set(&g);
+        set.expiration_time = 100;
+        let vaa = ParsedVaa::parse(&build_vaa(3, &[(0, &g[0]), (1, &g[1]), (2, &g[2])], b""))
+            .unwrap();
+
+        vaa.verify(&set, 99).unwrap();
+        assert_eq!(
+            vaa.verify(&set, 100).unwrap_err(),
+            BridgeError::GuardianSetExpired.into()
+        );
+    }
+}
@@ -0,0 +1,232 @@
+//! Cross-chain conformance vectors.
+//!
+//! Runs every case in the repository's `test-vectors.json` against the AMM
+//! math and the transfer message codec. The Hardhat suite reads the same
+//! file, so a value that differs between chains fails on both sides.
+//!
+//! `cargo test -p bridge --features test-vectors`
+#![cfg(feature = "test-vectors")]
+
+use std::str::FromStr;
+
+use amm::math;
+use bridge::errors::BridgeError;
+use bridge::message::TransferMessage;
+use bridge::state::BridgeConfig;
+use serde::Deserialize;
+
+const VECTORS: &str = include_str!("../../../../test-vectors.json");
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct Vectors {
+    version: String,
+    precision: String,
+    get_d: Vec<GetD>,
+    get_y: Vec<GetY>,
+    swap: Vec<Swap>,
+    bridge_fee: Vec<BridgeFee>,
+    transfer_message: Vec<Message>,
+    malformed_transfer_message: Vec<MalformedMessage>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct GetD {
+    name: String,
+    reserve_a: String,
+    reserve_b: String,
+    amplification: String,
+    expected_d: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct GetY {
+    name: String,
+    invariant_d: String,
+    new_reserve_x: String,
+    amplification: String,
+    expected_y: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct Swap {
+    name: String,
+    amount_in: String,
+    reserve_in: String,
+    reserve_out: String,
+    amplification: String,
+    fee_bps: String,
+    expected_amount_out: String,
+    /// Relative bound on `expected_amount_out`, for values quoted from the
+    /// design rather than computed.
+    #[serde(default)]
+    tolerance: Option<String>,
+    expected_fee: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct BridgeFee {
+    name: String,
+    amount: String,
+    fee_bps: String,
+    expected_fee: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct Message {
+    name: String,
+    version: u8,
+    transfer_id: String,
+    sender: String,
+    recipient: String,
+    destination_token: String,
+    amount_usd: String,
+    min_destination_amount: String,
+    nonce: String,
+    source_chain: u16,
+    timestamp: String,
+    encoded: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct MalformedMessage {
+    name: String,
+    encoded: String,
+    error: String,
+}
+
+fn vectors() -> Vectors {
+    let vectors: Vectors = serde_json::from_str(VECTORS).expect("test-vectors.json is malformed");
+    assert_eq!(vectors.version, "1.0");
+    assert_eq!(num::<u128>(&vectors.precision), math::PRECISION);
+    vectors
+}
+
+/// Integers are decimal strings so they survive JavaScript's number type.
+fn num<T: FromStr>(value: &str) -> T {
+    value
+        .parse()
+        .unwrap_or_else(|_| panic!("{value:?} is not a valid integer"))
+}
+
+/// Whether `actual` is within `tolerance`, a decimal fraction such as
+/// `"0.001"`, of `expected`.
+fn within(actual: u64, expected: u64, tolerance: &str) -> bool {
+    let (whole, fraction) = tolerance.split_once('.').unwrap_or((tolerance, ""));
+    let scale = 10u128.pow(fraction.len() as u32);
+    let bound: u128 = num::<u128>(whole) * scale + num::<u128>(&format!("0{fraction}"));
+    actual.abs_diff(expected) as u128 * scale <= expected as u128 * bound
+}
+
+fn hex(value: &str) -> Vec<u8> {
+    assert!(value.len() % 2 == 0, "{value:?} has an odd length");
+    (0..value.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&value[i..i + 2], 16).expect("invalid hex"))
+        .collect()
+}
+
+#[test]
+fn get_d() {
+    for case in vectors().get_d {
+        let d = math::get_d(
+            num(&case.reserve_a),
+            num(&case.reserve_b),
+            num(&case.amplification),
+        );
+        assert_eq!(d, Ok(num(&case.expected_d)), "{}", case.name);
+    }
+}
+
+#[test]
+fn get_y() {
+    for case in vectors().get_y {
+        let y = math::get_y(
+            num(&case.invariant_d),
+            num(&case.new_reserve_x),
+            num(&case.amplification),
+        );
+        assert_eq!(y, Ok(num(&case.expected_y)), "{}", case.name);
+    }
+}
+
+#[test]
+fn swap() {
+    for case in vectors().swap {
+        let quote = math::calculate_swap(
+            num(&case.amount_in),
+            num(&case.reserve_in),
+            num(&case.reserve_out),
+            num(&case.amplification),
+            num(&case.fee_bps),
+        )
+        .unwrap_or_else(|e| panic!("{}: {e:?}", case.name));
+        let expected_out: u64 = num(&case.expected_amount_out);
+        match &case.tolerance {
+            Some(tolerance) => assert!(
+                within(quote.amount_out, expected_out, tolerance),
+                "{}: {} is not within {tolerance} of {expected_out}",
+                case.name,
+                quote.amount_out
+            ),
+            None => assert_eq!(quote.amount_out, expected_out, "{}", case.name),
+        }
+        assert_eq!(quote.fee, num::<u64>(&case.expected_fee), "{}", case.name);
+    }
+}
+
+#[test]
+fn bridge_fee() {
+    for case in vectors().bridge_fee {
+        let config = BridgeConfig {
+            fee_bps: num(&case.fee_bps),
+            ..Default::default()
+        };
+        let fee = config.fee_for(num(&case.amount)).unwrap();
+        assert_eq!(fee, num::<u64>(&case.expected_fee), "{}", case.name);
+    }
+}
+
+#[test]
+fn transfer_message() {
+    for case in vectors().transfer_message {
+        let message = TransferMessage {
+            version: case.version,
+            transfer_id: hex(&case.transfer_id).try_into().unwrap(),
+            sender: hex(&case.sender),
+            recipient: hex(&case.recipient),
+            destination_token: hex(&case.destination_token).try_into().unwrap(),
+            amount_usd: num(&case.amount_usd),
+            min_destination_amount: num(&case.min_destination_amount),
+            nonce: num(&case.nonce),
+            source_chain: case.source_chain,
+            timestamp: num(&case.timestamp),
+        };
+        let encoded = hex(&case.encoded);
+        assert_eq!(message.encode().unwrap(), encoded, "{}", case.name);
+        assert_eq!(
+            TransferMessage::decode(&encoded).unwrap(),
+            message,
+            "{}",
+            case.name
+        );
+    }
+}
+
+#[test]
+fn malformed_transfer_message() {
+    for case in vectors().malformed_transfer_message {
+        let expected = BridgeError::ALL
+            .iter()
+            .find(|error| format!("{error:?}") == case.error)
+            .unwrap_or_else(|| panic!("{}: unknown error {}", case.name, case.error));
+        let error = TransferMessage::decode(&hex(&case.encoded)).unwrap_err();
+        assert_eq!(error, (*expected).into(), "{}", case.name);
+    }
+}